@@ -1,22 +1,74 @@
-use crate::error::Result;
+use crate::error::{Result, ScraperError};
 use headless_chrome::browser::tab::point::Point;
+use headless_chrome::Tab;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
-pub fn wttj_board_action(tab: &headless_chrome::Tab) -> Result<()> {
-    let element = tab.wait_for_element("button#axeptio_btn_dismiss")?;
-    element.click()?;
+pub type ActionFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+pub enum PageAction<T = Tab> {
+    Sync(fn(&T) -> Result<()>),
+    Async(Arc<dyn for<'a> Fn(&'a T) -> ActionFuture<'a> + Send + Sync>),
+}
+
+impl<T> Clone for PageAction<T> {
+    fn clone(&self) -> Self {
+        match self {
+            PageAction::Sync(action) => PageAction::Sync(*action),
+            PageAction::Async(action) => PageAction::Async(action.clone()),
+        }
+    }
+}
+
+impl<T> PageAction<T> {
+    pub fn new_async<F>(action: F) -> Self
+    where
+        F: for<'a> Fn(&'a T) -> ActionFuture<'a> + Send + Sync + 'static,
+    {
+        PageAction::Async(Arc::new(action))
+    }
+
+    pub async fn run(&self, tab: &T) -> Result<()> {
+        match self {
+            PageAction::Sync(action) => action(tab),
+            PageAction::Async(action) => action(tab).await,
+        }
+    }
+}
+
+pub fn wttj_board_action(tab: &Tab) -> Result<()> {
+    let fail = |stage| browser_error(tab, "WelcomeToTheJungle", stage);
+    let element = tab
+        .wait_for_element("button#axeptio_btn_dismiss")
+        .map_err(fail("cookie banner"))?;
+    element.click().map_err(fail("cookie banner"))?;
     let point = Point { x: 600.0, y: 190.0 };
-    tab.click_point(point)?;
-    tab.press_key(" ")?;
-    let element = tab.wait_for_element("div[data-testid='place-item-0'] div")?;
-    element.click()?;
+    tab.click_point(point).map_err(fail("location input"))?;
+    tab.press_key(" ").map_err(fail("location input"))?;
+    let element = tab
+        .wait_for_element("div[data-testid='place-item-0'] div")
+        .map_err(fail("location suggestion"))?;
+    element.click().map_err(fail("location suggestion"))?;
     thread::sleep(Duration::from_secs(2));
     Ok(())
 }
 
-pub fn hellowork_board_action(tab: &headless_chrome::Tab) -> Result<()> {
-    let element = tab.wait_for_element("button#hw-cc-notice-accept-btn")?;
-    element.click()?;
+pub fn hellowork_board_action(tab: &Tab) -> Result<()> {
+    let fail = |stage| browser_error(tab, "Hellowork", stage);
+    let element = tab
+        .wait_for_element("button#hw-cc-notice-accept-btn")
+        .map_err(fail("cookie banner"))?;
+    element.click().map_err(fail("cookie banner"))?;
     Ok(())
 }
+
+fn browser_error<'a>(
+    tab: &'a Tab,
+    board: &'a str,
+    stage: &'a str,
+) -> impl FnOnce(anyhow::Error) -> ScraperError + 'a {
+    move |e| ScraperError::from_browser(board, &tab.get_url(), stage, e)
+}
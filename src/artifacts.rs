@@ -0,0 +1,51 @@
+use crate::error::Result;
+use crate::models::Rule;
+use chrono::Utc;
+use headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption;
+use headless_chrome::Tab;
+use std::path::{Path, PathBuf};
+
+pub(crate) struct Failure<'a> {
+    pub board: &'a str,
+    pub url: &'a str,
+    pub reason: String,
+    pub html: Option<String>,
+    pub rule: Option<(&'static str, &'a Rule)>,
+}
+
+pub(crate) fn save_artifacts(dir: &Path, tab: &Tab, failure: Failure<'_>) -> Result<PathBuf> {
+    let path = dir.join(format!(
+        "{}-{}",
+        failure.board,
+        Utc::now().format("%Y%m%dT%H%M%S%.3f")
+    ));
+    std::fs::create_dir_all(&path)?;
+
+    let html = match failure.html {
+        Some(html) => Some(html),
+        None => tab.get_content().ok(),
+    };
+    if let Some(html) = html {
+        std::fs::write(path.join("page.html"), html)?;
+    }
+    if let Ok(png) = tab.capture_screenshot(CaptureScreenshotFormatOption::Png, None, None, true) {
+        std::fs::write(path.join("screenshot.png"), png)?;
+    }
+
+    let mut context = format!(
+        "board: {}\nurl: {}\ntab url: {}\nreason: {}\n",
+        failure.board,
+        failure.url,
+        tab.get_url(),
+        failure.reason
+    );
+    if let Some((field, rule)) = failure.rule {
+        context.push_str(&format!(
+            "field: {}\nselects: {}\nn: {:?}\nreturns: {:?}\ntransforms: {:?}\n",
+            field, rule.selects, rule.n, rule.returns, rule.transforms
+        ));
+    }
+    std::fs::write(path.join("context.txt"), context)?;
+
+    Ok(path)
+}
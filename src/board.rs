@@ -1,30 +1,62 @@
+use crate::artifacts::{save_artifacts, Failure};
 use crate::constants::BoardConfig;
+use crate::dates::{parse_date_label, Clock, PostedDate, SystemClock};
 use crate::error::{Result, ScraperError};
+use crate::experience;
+use crate::facts;
+use crate::health::{
+    measure_cards, save_sample, BoardHealth, FieldHealth, HealthCheckOptions, HealthReport,
+};
+use crate::jsonld;
+use crate::location;
+use crate::markdown;
 use crate::models::Board;
-use crate::models::{Job, JobSearchParams, PageQuery, Rule, RuleReturns};
-use crate::transforms::parse_date;
-use chrono::Utc;
+use crate::models::{
+    CardCheck, CareerSite, Job, JobSearchParams, KeywordField, PageQuery, Pagination, Radius, Rule,
+    RuleReturns, SearchReport, Selectors, Selects,
+};
+use crate::scoring::{self, Profile};
+use crate::skills::Taxonomy;
+use crate::transforms;
 use headless_chrome::Browser;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 use url::Url;
 
+const BLOCK_MARKERS: &[&str] = &[
+    "captcha",
+    "cf-challenge",
+    "challenge-platform",
+    "datadome",
+    "access denied",
+];
+const RATE_LIMIT_MARKERS: &[&str] = &["too many requests", "error 429", "http 429"];
+
 pub struct BoardScraper {
     browser: Browser,
     config: BoardConfig,
     params: JobSearchParams,
+    debug_dir: Option<PathBuf>,
+    clock: Arc<dyn Clock>,
+    career_site: Option<CareerSite>,
 }
 
 impl BoardScraper {
     pub fn new() -> Result<Self> {
-        let browser = Browser::default()
-            .map_err(|e| ScraperError::BrowserError(format!("Failed to launch browser: {}", e)))?;
+        let config = crate::constants::HELLOWORK.clone();
+        let browser = launch_browser(Board::All.as_str())?;
 
         Ok(Self {
             browser,
-            config: crate::constants::HELLOWORK.clone(),
+            config,
             params: JobSearchParams::default(),
+            debug_dir: None,
+            clock: Arc::new(SystemClock),
+            career_site: None,
         })
     }
 
@@ -43,90 +75,460 @@ impl BoardScraper {
         self
     }
 
+    pub fn max_pages(mut self, max_pages: u32) -> Self {
+        self.params.max_pages = max_pages;
+        self
+    }
+
+    pub fn radius<S: Into<String>>(mut self, center: S, km: f64) -> Self {
+        self.params.radius = Some(Radius::new(center, km));
+        self
+    }
+
+    pub fn taxonomy(mut self, taxonomy: Taxonomy) -> Self {
+        self.params.taxonomy = taxonomy;
+        self
+    }
+
+    pub fn require_skill<S: Into<String>>(mut self, skill: S) -> Self {
+        self.params.required_skills.push(skill.into());
+        self
+    }
+
+    pub fn exclude_skill<S: Into<String>>(mut self, skill: S) -> Self {
+        self.params.excluded_skills.push(skill.into());
+        self
+    }
+
+    pub fn require_keyword<S: Into<String>>(mut self, field: KeywordField, keyword: S) -> Self {
+        self.params
+            .keywords
+            .field_mut(field)
+            .must_contain
+            .push(keyword.into());
+        self
+    }
+
+    pub fn exclude_keyword<S: Into<String>>(mut self, field: KeywordField, keyword: S) -> Self {
+        self.params
+            .keywords
+            .field_mut(field)
+            .must_not_contain
+            .push(keyword.into());
+        self
+    }
+
+    pub fn allow_company<S: Into<String>>(mut self, company: S) -> Self {
+        self.params.companies.allow.push(company.into());
+        self
+    }
+
+    pub fn deny_company<S: Into<String>>(mut self, company: S) -> Self {
+        self.params.companies.deny.push(company.into());
+        self
+    }
+
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.params.profile = Some(profile);
+        self
+    }
+
+    pub fn sort_by_score(mut self) -> Self {
+        self.params.sort_by_score = true;
+        self
+    }
+
+    pub fn debug_artifacts<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.debug_dir = Some(dir.into());
+        self
+    }
+
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     pub fn board(mut self, board: Board) -> Self {
-        self.params.board = board.clone();
-        self.config = match self.params.board {
-            Board::Hellowork => crate::constants::HELLOWORK.clone(),
-            Board::Linkedin => crate::constants::LINKEDIN.clone(),
-            Board::WTTJ => crate::constants::WTTJ.clone(),
-            Board::All => crate::constants::HELLOWORK.clone(),
-        };
+        self.params.board = board;
+        self.config = builtin_config(board).unwrap_or_else(|| crate::constants::HELLOWORK.clone());
+        self
+    }
+
+    pub fn build(self) -> Result<Self> {
+        if let Some(site) = &self.career_site {
+            site.validate()?;
+        }
+        self.check_radius()?;
+        Ok(self)
+    }
+
+    pub fn career_site(mut self, site: CareerSite) -> Self {
+        self.career_site = Some(site);
         self
     }
 
     pub async fn search(self) -> Result<Vec<Job>> {
-        if let Board::All = self.params.board {
-            let mut all_jobs = Vec::new();
+        let all_boards = self.career_site.is_none() && matches!(self.params.board, Board::All);
+        self.search_report().await.into_result(all_boards)
+    }
+
+    pub async fn search_report(self) -> SearchReport {
+        let mut report = SearchReport::default();
+        if let Some(site) = &self.career_site {
+            if let Err(e) = self.scrape_career_site(site, &mut report).await {
+                report.errors.push(e);
+            }
+        } else if let Board::All = self.params.board {
             for board in Board::variants() {
-                let scraper = self.create_for_board(board)?;
-                match scraper.search_board().await {
-                    Ok(jobs) => all_jobs.extend(jobs),
-                    Err(e) => eprintln!("Error scraping {:?}: {}", board, e),
+                match self.create_for_board(board) {
+                    Ok(scraper) => scraper.search_board(&mut report).await,
+                    Err(e) => report.errors.push(e),
                 }
             }
-            Ok(all_jobs)
         } else {
-            self.search_board().await
+            self.search_board(&mut report).await;
         }
+        if self.params.sort_by_score {
+            scoring::sort_by_score(&mut report.jobs);
+        }
+        report
     }
 
-    async fn search_board(self) -> Result<Vec<Job>> {
-        let mut jobs = Vec::new();
+    async fn search_board(&self, report: &mut SearchReport) {
+        if let Err(e) = self.check_radius() {
+            report.errors.push(e);
+            return;
+        }
+        if let Err(e) = self.scrape_board(report).await {
+            report.errors.push(e);
+        }
+    }
+
+    async fn scrape_board(&self, report: &mut SearchReport) -> Result<()> {
         let mut count: u32 = 0;
         let mut offset: u32 = 1;
         let limit = self.params.limit;
+        let mut pagination = Pagination::new(self.params.max_pages);
 
         let tab = self
             .browser
             .new_tab()
-            .map_err(|e| ScraperError::BrowserError(format!("Failed to create tab: {}", e)))?;
+            .map_err(|e| ScraperError::from_browser(self.config.name, "", "new tab", e))?;
 
         let mut actions_taken = false;
         while count < limit {
             let board_url = self.url(PageQuery::Board(&self.params), Some(offset))?;
-            tab.navigate_to(&board_url)
-                .map_err(|e| ScraperError::BrowserError(format!("Navigation failed: {}", e)))?;
+            self.navigate(&tab, &board_url)?;
             sleep(Duration::from_secs(2)).await;
 
             if !actions_taken {
-                if let Some(action_fn) = self.config.board_page_action {
-                    action_fn(&tab)?;
+                if let Some(action) = &self.config.board_page_action {
+                    action.run(&tab).await?;
                 }
                 actions_taken = true;
             }
-            let html_content = self.get_html(&tab)?;
+            let html_content = self.get_html(&tab, &board_url)?;
             let document = Html::parse_document(&html_content);
-            let selector =
-                Selector::parse(&self.config.selectors.card.selects).expect("Invalid selector");
-            let job_cards: Vec<_> = document.select(&selector).collect();
-            if job_cards.is_empty() {
-                eprintln!("No job cards found on page {}", offset);
+            let Some((alternative, card_selector, job_cards)) = self.select_cards(&document)?
+            else {
+                let reason = format!("No job cards found on page {}", offset);
+                self.save_debug_artifacts(
+                    &tab,
+                    Failure {
+                        board: self.config.name,
+                        url: &board_url,
+                        reason: reason.clone(),
+                        html: Some(html_content.clone()),
+                        rule: Some(("card", &self.config.selectors.card)),
+                    },
+                    report,
+                );
+                self.check_blocked(&board_url, &html_content)?;
+                if offset == 1 {
+                    report.warnings.push(ScraperError::ExtractionFailed {
+                        board: self.config.name.to_string(),
+                        url: board_url,
+                        field: "card".to_string(),
+                        message: reason,
+                    });
+                }
                 break;
-            }
+            };
+            report.record_match(
+                self.config.name,
+                "card",
+                alternative,
+                &card_selector.to_string(),
+            );
+            let mut new_cards = 0;
             for card in job_cards {
                 let card_html = Html::parse_fragment(&card.html());
-                let job = self.build_job(&tab, &card_html).await?;
-                jobs.push(job);
+                let key = extract_from_rule(&card_html, &self.config.selectors.id)
+                    .unwrap_or_else(|| card.html());
+                if !pagination.is_new(&key) {
+                    continue;
+                }
+                new_cards += 1;
+                let check = check_card(&card_html, &self.config.selectors, &self.params);
+                if let CardCheck::Unlocated { kept } = check {
+                    self.warn_unlocated(report, &board_url, kept);
+                }
+                if !check.is_kept() {
+                    continue;
+                }
+                match self.build_job(&tab, &card_html, &board_url, report).await {
+                    Ok(job) if !self.wants(&job) => continue,
+                    Ok(job) => report.jobs.push(self.scored(job)),
+                    Err(e) => {
+                        self.save_failure_artifacts(&tab, &e, &card.html(), report);
+                        report.card_errors.push(e);
+                    }
+                }
                 count += 1;
                 if count >= limit {
                     break;
                 }
             }
+            if !pagination.next_page(new_cards) {
+                break;
+            }
             offset += 1;
         }
-        Ok(jobs)
+        Ok(())
     }
 
-    fn create_for_board(&self, board: Board) -> Result<Self> {
-        let config = match board {
-            Board::Hellowork => crate::constants::HELLOWORK.clone(),
-            Board::Linkedin => crate::constants::LINKEDIN.clone(),
-            Board::WTTJ => crate::constants::WTTJ.clone(),
-            Board::All => panic!("Board::All should not be used here"),
+    async fn scrape_career_site(&self, site: &CareerSite, report: &mut SearchReport) -> Result<()> {
+        site.validate()?;
+        self.check_radius()?;
+        let tab = self
+            .browser
+            .new_tab()
+            .map_err(|e| ScraperError::from_browser(&site.name, "", "new tab", e))?;
+
+        self.navigate(&tab, &site.listing_url)?;
+        sleep(Duration::from_secs(2)).await;
+        let html_content = self.get_html(&tab, &site.listing_url)?;
+        let links = posting_links(&Html::parse_document(&html_content), site);
+        if links.is_empty() {
+            self.save_debug_artifacts(
+                &tab,
+                Failure {
+                    board: &site.name,
+                    url: &site.listing_url,
+                    reason: "No posting links found".to_string(),
+                    html: Some(html_content.clone()),
+                    rule: None,
+                },
+                report,
+            );
+            self.check_blocked(&site.listing_url, &html_content)?;
+            return Err(ScraperError::SelectorNotFound {
+                board: site.name.clone(),
+                url: site.listing_url.clone(),
+                field: "link".to_string(),
+                selector: site.link_selector.clone(),
+            });
+        }
+
+        for url in links.into_iter().take(self.params.limit as usize) {
+            match self.build_posting_job(&tab, site, url, report).await {
+                Ok(Some(job)) => report.jobs.push(self.scored(job)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.save_failure_artifacts(&tab, &e, "", report);
+                    report.card_errors.push(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn build_posting_job(
+        &self,
+        tab: &headless_chrome::Tab,
+        site: &CareerSite,
+        url: String,
+        report: &mut SearchReport,
+    ) -> Result<Option<Job>> {
+        self.navigate(tab, &url)?;
+        sleep(Duration::from_secs(1)).await;
+        let page = Html::parse_document(&self.get_html(tab, &url)?);
+        let posting = jsonld::extract_job_posting(&page, self.clock.as_ref()).ok_or_else(|| {
+            ScraperError::ExtractionFailed {
+                board: site.name.clone(),
+                url: url.clone(),
+                field: "JobPosting".to_string(),
+                message: "No schema.org JobPosting found in the page".to_string(),
+            }
+        })?;
+
+        let location = posting.location.unwrap_or_default();
+        let normalized_location = location::normalize(&location);
+        if let Some(radius) = &self.params.radius {
+            let check = radius.check(normalized_location.as_ref());
+            if let CardCheck::Unlocated { kept } = check {
+                self.warn_unlocated(report, &url, kept);
+            }
+            if !check.is_kept() {
+                return Ok(None);
+            }
+        }
+        let title = posting.title.unwrap_or_default();
+        let description = posting.description.unwrap_or_default();
+        let (experience_level, experience_years) =
+            experience::infer(posting.experience.as_deref(), &title, &description);
+        let skills = self.params.taxonomy.extract(&[&title, &description]);
+        let job = Job {
+            id: url.clone(),
+            title,
+            company: posting.company.unwrap_or_default(),
+            distance_km: self.distance_km(normalized_location.as_ref()),
+            normalized_location,
+            location,
+            description,
+            date_posted: posting
+                .date_posted
+                .map(PostedDate::from)
+                .unwrap_or_default(),
+            url,
+            source: site.name.clone(),
+            company_url: posting.company_url,
+            company_logo: posting.company_logo,
+            contract: posting.employment_type,
+            salary: posting.salary,
+            valid_through: posting.valid_through.map(|d| d.datetime),
+            experience_level,
+            experience_years,
+            skills,
+            ..Default::default()
+        };
+        Ok(Some(job).filter(|job| self.wants(job)))
+    }
+
+    pub async fn health_check(self, options: &HealthCheckOptions) -> HealthReport {
+        let mut report = HealthReport {
+            threshold: options.threshold,
+            ..Default::default()
         };
+        if let Board::All = self.params.board {
+            for board in Board::variants() {
+                match self.create_for_board(board) {
+                    Ok(scraper) => scraper.check_board(options, &mut report).await,
+                    Err(e) => report.errors.push(e),
+                }
+            }
+        } else {
+            self.check_board(options, &mut report).await;
+        }
+        report
+    }
 
-        let browser = Browser::default()
-            .map_err(|e| ScraperError::BrowserError(format!("Failed to launch browser: {}", e)))?;
+    async fn check_board(&self, options: &HealthCheckOptions, report: &mut HealthReport) {
+        match self.measure_board(options).await {
+            Ok(health) => report.boards.push(health),
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    async fn measure_board(&self, options: &HealthCheckOptions) -> Result<BoardHealth> {
+        let selectors = &self.config.selectors;
+        let tab = self
+            .browser
+            .new_tab()
+            .map_err(|e| ScraperError::from_browser(self.config.name, "", "new tab", e))?;
+
+        let board_url = self.url(PageQuery::Board(&self.params), Some(1))?;
+        self.navigate(&tab, &board_url)?;
+        sleep(Duration::from_secs(2)).await;
+        if let Some(action) = &self.config.board_page_action {
+            action.run(&tab).await?;
+        }
+
+        let html_content = self.get_html(&tab, &board_url)?;
+        let document = Html::parse_document(&html_content);
+        let cards: Vec<Html> = self
+            .select_cards(&document)?
+            .map(|(_, _, cards)| cards)
+            .unwrap_or_default()
+            .into_iter()
+            .take(options.sample_size)
+            .map(|card| Html::parse_fragment(&card.html()))
+            .collect();
+        if cards.is_empty() {
+            self.check_blocked(&board_url, &html_content)?;
+        }
+
+        let mut fields = vec![FieldHealth {
+            field: "card",
+            selector: selectors.card.selects.to_string(),
+            matched: usize::from(!cards.is_empty()),
+            total: 1,
+            sample: None,
+        }];
+        fields.extend(measure_cards(selectors, &cards));
+
+        let mut description = FieldHealth {
+            field: "description",
+            selector: selectors.description.selects.to_string(),
+            matched: 0,
+            total: 0,
+            sample: None,
+        };
+        for card in &cards {
+            let Some(id) = self.value(card, &selectors.id).filter(|id| !id.is_empty()) else {
+                continue;
+            };
+            let url = self.url(PageQuery::Job(&id), None)?;
+            self.navigate(&tab, &url)?;
+            sleep(Duration::from_secs(1)).await;
+
+            let page = self.get_html(&tab, &url)?;
+            description.total += 1;
+            if self
+                .value(&Html::parse_document(&page), &selectors.description)
+                .is_some_and(|v| !v.trim().is_empty())
+            {
+                description.matched += 1;
+            } else if description.sample.is_none() {
+                description.sample = Some(save_sample(
+                    &options.samples_dir,
+                    self.config.name,
+                    "detail",
+                    &page,
+                )?);
+            }
+        }
+        fields.push(description);
+
+        let mut listing_sample = None;
+        for field in fields.iter_mut() {
+            if field.sample.is_none() && field.is_drifted(options.threshold) {
+                if listing_sample.is_none() {
+                    listing_sample = Some(save_sample(
+                        &options.samples_dir,
+                        self.config.name,
+                        "listing",
+                        &html_content,
+                    )?);
+                }
+                field.sample = listing_sample.clone();
+            }
+        }
+
+        Ok(BoardHealth {
+            board: self.config.name.to_string(),
+            url: board_url,
+            fields,
+        })
+    }
+
+    fn create_for_board(&self, board: Board) -> Result<Self> {
+        let config = builtin_config(board).ok_or_else(|| ScraperError::InvalidConfig {
+            board: board.as_str().to_string(),
+            problems: vec!["Board::All has no configuration of its own".to_string()],
+        })?;
+
+        let browser = launch_browser(config.name)?;
 
         Ok(Self {
             browser,
@@ -135,85 +537,379 @@ impl BoardScraper {
                 board,
                 ..self.params.clone()
             },
+            debug_dir: self.debug_dir.clone(),
+            clock: self.clock.clone(),
+            career_site: None,
         })
     }
 
-    async fn build_job(&self, tab: &headless_chrome::Tab, card_html: &Html) -> Result<Job> {
+    async fn build_job(
+        &self,
+        tab: &headless_chrome::Tab,
+        card_html: &Html,
+        board_url: &str,
+        report: &mut SearchReport,
+    ) -> Result<Job> {
         let selectors = &self.config.selectors;
 
         let id = self
-            .extract_from_rule(card_html, &selectors.id)
-            .unwrap_or_default();
+            .extract(card_html, "id", &selectors.id, report)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| ScraperError::SelectorNotFound {
+                board: self.config.name.to_string(),
+                url: board_url.to_string(),
+                field: "id".to_string(),
+                selector: selectors.id.selects.to_string(),
+            })?;
         let url = self.url(PageQuery::Job(&id), None)?;
-        tab.navigate_to(&url)
-            .map_err(|e| ScraperError::BrowserError(format!("Failed to navigate to job: {}", e)))?;
+        self.navigate(tab, &url)?;
 
         sleep(Duration::from_secs(1)).await;
 
-        let job_html_content = self.get_html(tab)?;
+        let job_html_content = self.get_html(tab, &url)?;
         let job_html = Html::parse_document(&job_html_content);
-        let description = self
-            .extract_from_rule(&job_html, &selectors.description)
-            .unwrap_or_default();
+        let posting =
+            jsonld::extract_job_posting(&job_html, self.clock.as_ref()).unwrap_or_default();
+        let (description, sections) = extract_description(
+            &job_html,
+            selectors,
+            self.clock.as_ref(),
+            |field, alternative, matched| {
+                report.record_match(
+                    self.config.name,
+                    field,
+                    alternative,
+                    &matched.selects.to_string(),
+                )
+            },
+        );
 
-        let title = self
-            .extract_from_rule(card_html, &selectors.title)
+        let facts = extract_facts(
+            &job_html,
+            selectors,
+            self.clock.as_ref(),
+            |field, alternative, matched| {
+                report.record_match(
+                    self.config.name,
+                    field,
+                    alternative,
+                    &matched.selects.to_string(),
+                )
+            },
+        );
+
+        let date_label = self.extract(card_html, "date_posted", &selectors.date_posted, report);
+        let date_posted = match posting.date_posted.or_else(|| {
+            date_label
+                .as_deref()
+                .and_then(|label| parse_date_label(label, self.clock.as_ref()))
+        }) {
+            Some(parsed) => parsed.into(),
+            None => {
+                report.warnings.push(ScraperError::ExtractionFailed {
+                    board: self.config.name.to_string(),
+                    url: url.clone(),
+                    field: "date_posted".to_string(),
+                    message: match date_label {
+                        Some(label) => format!("Unrecognised date label {:?}", label),
+                        None => format!(
+                            "Selector `{}` matched nothing",
+                            selectors.date_posted.selects
+                        ),
+                    },
+                });
+                PostedDate::unknown()
+            }
+        };
+
+        let location = posting
+            .location
+            .or_else(|| self.extract(card_html, "location", &selectors.location, report))
+            .or_else(|| facts.get("location").cloned())
+            .unwrap_or_default();
+        let normalized_location = location::normalize(&location);
+        let title = posting
+            .title
+            .or_else(|| self.extract(card_html, "title", &selectors.title, report))
             .unwrap_or_default()
             .replace('\n', " ");
-        Ok(Job {
+        let company = posting
+            .company
+            .or_else(|| self.extract(card_html, "company", &selectors.company, report))
+            .or_else(|| facts.get("company").cloned())
+            .unwrap_or_default();
+        let mut optional = |field: &str, rule: &Option<Rule>| {
+            self.extract_optional([card_html, &job_html], field, rule.as_ref(), report)
+        };
+        let apply_url = optional("apply_url", &selectors.apply_url);
+        let company_url = posting
+            .company_url
+            .or_else(|| optional("company_url", &selectors.company_url));
+        let company_logo = posting
+            .company_logo
+            .or_else(|| optional("company_logo", &selectors.company_logo));
+        let company_size = optional("company_size", &selectors.company_size)
+            .or_else(|| facts.get("company_size").cloned());
+        let company_sector = optional("company_sector", &selectors.company_sector)
+            .or_else(|| facts.get("company_sector").cloned());
+        let tags = optional("tags", &selectors.tags)
+            .map(|value| split_tags(&value))
+            .unwrap_or_default();
+        let contract = posting
+            .employment_type
+            .or_else(|| facts.get("contract").cloned());
+        let salary = posting
+            .salary
+            .or_else(|| facts.get("salary").and_then(|s| facts::parse_salary(s)));
+        let description = posting.description.unwrap_or(description);
+        let experience_label = posting
+            .experience
+            .or_else(|| facts.get("experience").cloned());
+        let (experience_level, experience_years) =
+            experience::infer(experience_label.as_deref(), &title, &description);
+        let skills = self.params.taxonomy.extract(&[&title, &description]);
+        let job = Job {
             id,
             title,
-            company: self
-                .extract_from_rule(card_html, &selectors.company)
-                .unwrap_or_default(),
-            location: self
-                .extract_from_rule(card_html, &selectors.location)
-                .unwrap_or_default(),
+            company,
+            company_url,
+            company_logo,
+            company_size,
+            company_sector,
+            distance_km: self.distance_km(normalized_location.as_ref()),
+            normalized_location,
+            location,
             description,
+            sections,
             url,
-            date_posted: self
-                .extract_from_rule(card_html, &selectors.date_posted)
-                .map(|d| parse_date(&d))
-                .unwrap_or_else(|| Utc::now().date_naive()),
+            apply_url,
+            date_posted,
             source: self.config.name.to_string(),
-        })
+            tags,
+            contract,
+            salary,
+            valid_through: posting.valid_through.map(|d| d.datetime),
+            experience_level,
+            experience_years,
+            skills,
+            score: None,
+            facts,
+        };
+        if let Some(field) = missing_field(&job) {
+            let rule = match field {
+                "title" => &selectors.title,
+                "company" => &selectors.company,
+                _ => &selectors.description,
+            };
+            return Err(ScraperError::SelectorNotFound {
+                board: self.config.name.to_string(),
+                url: job.url,
+                field: field.to_string(),
+                selector: rule.selects.to_string(),
+            });
+        }
+        Ok(job)
+    }
+
+    fn navigate(&self, tab: &headless_chrome::Tab, url: &str) -> Result<()> {
+        tab.navigate_to(url).map_err(|e| {
+            if e.downcast_ref::<headless_chrome::util::Timeout>().is_some() {
+                ScraperError::from_browser(self.board_name(), url, "navigation", e)
+            } else {
+                ScraperError::Navigation {
+                    board: self.board_name().to_string(),
+                    url: url.to_string(),
+                    message: e.to_string(),
+                }
+            }
+        })?;
+        Ok(())
     }
 
-    fn extract_from_rule(&self, document: &Html, selector_rule: &Rule) -> Option<String> {
-        let selector = Selector::parse(selector_rule.selects).ok()?;
-        let elements: Vec<_> = document.select(&selector).collect();
-        if elements.is_empty() {
-            return None;
+    fn get_html(&self, tab: &headless_chrome::Tab, url: &str) -> Result<String> {
+        tab.get_content()
+            .map_err(|e| ScraperError::from_browser(self.board_name(), url, "reading page", e))
+    }
+
+    fn scored(&self, mut job: Job) -> Job {
+        if let Some(profile) = &self.params.profile {
+            job.score = Some(scoring::score(&job, profile, &self.params.taxonomy));
         }
+        job
+    }
 
-        let (start, end) = match selector_rule.n {
-            Some((s, e)) => (s, e.min(elements.len())),
-            None => (0, 1),
+    fn wants(&self, job: &Job) -> bool {
+        self.params.keywords.matches(job)
+            && self.params.companies.matches(&job.company)
+            && self.params.taxonomy.satisfies(
+                &job.skills,
+                &self.params.required_skills,
+                &self.params.excluded_skills,
+            )
+    }
+
+    fn board_name(&self) -> &str {
+        match &self.career_site {
+            Some(site) => &site.name,
+            None => self.config.name,
+        }
+    }
+
+    fn check_radius(&self) -> Result<()> {
+        match &self.params.radius {
+            Some(radius) if radius.coordinates.is_none() => Err(ScraperError::InvalidConfig {
+                board: self.board_name().to_string(),
+                problems: vec![format!(
+                    "radius center `{}` is not a known place",
+                    radius.center
+                )],
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn distance_km(&self, location: Option<&location::Location>) -> Option<f64> {
+        self.params.radius.as_ref()?.distance_km(location)
+    }
+
+    fn warn_unlocated(&self, report: &mut SearchReport, url: &str, kept: bool) {
+        let Some(radius) = &self.params.radius else {
+            return;
         };
-        let slice = &elements[start..end];
-
-        let mut values: Vec<String> = match &selector_rule.returns {
-            RuleReturns::Text => slice
-                .iter()
-                .map(|el| el.text().collect::<Vec<_>>().join("\n").trim().to_string())
-                .collect(),
-            RuleReturns::Attribute(attr) => slice
-                .iter()
-                .filter_map(|el| el.value().attr(attr).map(|v| v.to_string()))
-                .collect(),
-            RuleReturns::Html => slice.iter().map(|el| el.html()).collect(),
+        report.warnings.push(ScraperError::ExtractionFailed {
+            board: self.board_name().to_string(),
+            url: url.to_string(),
+            field: "location".to_string(),
+            message: format!(
+                "Location could not be placed within {} km of `{}`, {}",
+                radius.km,
+                radius.center,
+                if kept { "kept" } else { "dropped" }
+            ),
+        });
+    }
+
+    fn save_failure_artifacts(
+        &self,
+        tab: &headless_chrome::Tab,
+        error: &ScraperError,
+        card: &str,
+        report: &mut SearchReport,
+    ) {
+        let (html, rule) = match error {
+            ScraperError::SelectorNotFound { field, .. } => (
+                Some(card.to_string()).filter(|_| field != "description"),
+                self.config
+                    .selectors
+                    .rules()
+                    .into_iter()
+                    .find(|(name, _)| name == field),
+            ),
+            _ => (None, None),
         };
+        let url = error
+            .url()
+            .map(str::to_string)
+            .unwrap_or_else(|| tab.get_url());
+        self.save_debug_artifacts(
+            tab,
+            Failure {
+                board: self.board_name(),
+                url: &url,
+                reason: error.to_string(),
+                html,
+                rule,
+            },
+            report,
+        );
+    }
+
+    fn save_debug_artifacts(
+        &self,
+        tab: &headless_chrome::Tab,
+        failure: Failure<'_>,
+        report: &mut SearchReport,
+    ) {
+        if let Some(dir) = &self.debug_dir {
+            match save_artifacts(dir, tab, failure) {
+                Ok(path) => report.artifacts.push(path),
+                Err(e) => report.warnings.push(e),
+            }
+        }
+    }
 
-        if let Some(transform_fn) = selector_rule.transforms {
-            values = values.iter().map(|v| transform_fn(v)).collect();
+    fn select_cards<'a>(
+        &self,
+        document: &'a Html,
+    ) -> Result<Option<(usize, Selects, Vec<ElementRef<'a>>)>> {
+        for (alternative, rule) in self.config.selectors.card.candidates().enumerate() {
+            let cards = rule
+                .selects
+                .select(document)
+                .map_err(|e| ScraperError::InvalidConfig {
+                    board: self.config.name.to_string(),
+                    problems: vec![format!(
+                        "card selector `{}` does not parse: {}",
+                        rule.selects, e
+                    )],
+                })?;
+            if !cards.is_empty() {
+                return Ok(Some((alternative, rule.selects, cards)));
+            }
         }
+        Ok(None)
+    }
 
-        Some(values.join("\n\n"))
+    fn extract(
+        &self,
+        document: &Html,
+        field: &str,
+        rule: &Rule,
+        report: &mut SearchReport,
+    ) -> Option<String> {
+        let (alternative, matched, value) = extract_match(document, rule, self.clock.as_ref())?;
+        report.record_match(
+            self.config.name,
+            field,
+            alternative,
+            &matched.selects.to_string(),
+        );
+        Some(value)
     }
 
-    fn get_html(&self, tab: &headless_chrome::Tab) -> Result<String> {
-        tab.get_content()
-            .map_err(|e| ScraperError::BrowserError(format!("Failed to get HTML: {}", e)))
+    fn value(&self, document: &Html, rule: &Rule) -> Option<String> {
+        extract_match(document, rule, self.clock.as_ref()).map(|(_, _, value)| value)
+    }
+
+    fn extract_optional(
+        &self,
+        documents: [&Html; 2],
+        field: &str,
+        rule: Option<&Rule>,
+        report: &mut SearchReport,
+    ) -> Option<String> {
+        let rule = rule?;
+        documents
+            .into_iter()
+            .find_map(|document| self.extract(document, field, rule, report))
+    }
+
+    fn check_blocked(&self, url: &str, html: &str) -> Result<()> {
+        let content = html.to_lowercase();
+        if RATE_LIMIT_MARKERS.iter().any(|m| content.contains(m)) {
+            return Err(ScraperError::RateLimited {
+                board: self.board_name().to_string(),
+                url: url.to_string(),
+                retry_after: None,
+            });
+        }
+        if BLOCK_MARKERS.iter().any(|m| content.contains(m)) {
+            return Err(ScraperError::Blocked {
+                board: self.board_name().to_string(),
+                url: url.to_string(),
+            });
+        }
+        Ok(())
     }
 
     fn url(&self, query: PageQuery<'_>, offset: Option<u32>) -> Result<String> {
@@ -246,3 +942,256 @@ impl BoardScraper {
         Ok(url.to_string())
     }
 }
+
+fn builtin_config(board: Board) -> Option<BoardConfig> {
+    match board {
+        Board::Hellowork => Some(crate::constants::HELLOWORK.clone()),
+        Board::Linkedin => Some(crate::constants::LINKEDIN.clone()),
+        Board::WTTJ => Some(crate::constants::WTTJ.clone()),
+        Board::All => None,
+    }
+}
+
+fn launch_browser(board: &str) -> Result<Browser> {
+    Browser::default().map_err(|e| ScraperError::Launch {
+        board: board.to_string(),
+        message: e.to_string(),
+    })
+}
+
+pub fn missing_field(job: &Job) -> Option<&'static str> {
+    [
+        ("title", &job.title),
+        ("company", &job.company),
+        ("description", &job.description),
+    ]
+    .into_iter()
+    .find(|(_, value)| value.trim().is_empty())
+    .map(|(field, _)| field)
+}
+
+pub fn check_card(card_html: &Html, selectors: &Selectors, params: &JobSearchParams) -> CardCheck {
+    let mut check = CardCheck::Keep;
+    if let Some(radius) = &params.radius {
+        let location =
+            extract_from_rule(card_html, &selectors.location).and_then(|l| location::normalize(&l));
+        check = radius.check(location.as_ref());
+        if !check.is_kept() {
+            return check;
+        }
+    }
+    if !params.companies.is_empty() {
+        if let Some(company) = extract_from_rule(card_html, &selectors.company) {
+            if !params.companies.matches(&company) {
+                return CardCheck::Reject;
+            }
+        }
+    }
+    for (field, rule) in [
+        (KeywordField::Title, &selectors.title),
+        (KeywordField::Company, &selectors.company),
+    ] {
+        let filter = params.keywords.field(field);
+        if filter.is_empty() {
+            continue;
+        }
+        if let Some(value) = extract_from_rule(card_html, rule) {
+            if !filter.matches(&value) {
+                return CardCheck::Reject;
+            }
+        }
+    }
+    check
+}
+
+pub fn posting_links(document: &Html, site: &CareerSite) -> Vec<String> {
+    let (Ok(base), Ok(selector)) = (
+        Url::parse(&site.listing_url),
+        Selector::parse(&site.link_selector),
+    ) else {
+        return Vec::new();
+    };
+    let mut links: Vec<String> = Vec::new();
+    for href in document
+        .select(&selector)
+        .filter_map(|link| link.value().attr("href"))
+    {
+        if let Ok(mut url) = base.join(href.trim()) {
+            url.set_fragment(None);
+            let url = url.to_string();
+            if !links.contains(&url) {
+                links.push(url);
+            }
+        }
+    }
+    links
+}
+
+pub fn extract_description(
+    document: &Html,
+    selectors: &Selectors,
+    clock: &dyn Clock,
+    mut on_match: impl FnMut(&str, usize, &Rule),
+) -> (String, BTreeMap<String, String>) {
+    let mut sections = BTreeMap::new();
+    for (name, rule) in selectors.sections {
+        if let Some((alternative, matched, text)) = extract_match(document, rule, clock) {
+            on_match(name, alternative, matched);
+            sections.insert(name.to_string(), text);
+        }
+    }
+    let description = match extract_match(document, &selectors.description, clock) {
+        Some((alternative, matched, text)) => {
+            on_match("description", alternative, matched);
+            text
+        }
+        None => String::new(),
+    };
+    (description, sections)
+}
+
+pub fn extract_facts(
+    document: &Html,
+    selectors: &Selectors,
+    clock: &dyn Clock,
+    mut on_match: impl FnMut(&str, usize, &Rule),
+) -> BTreeMap<String, String> {
+    let mut facts = BTreeMap::new();
+    let Some(rule) = &selectors.facts else {
+        return facts;
+    };
+    let Some((alternative, matched, pairs)) =
+        rule.candidates()
+            .enumerate()
+            .find_map(|(alternative, candidate)| {
+                let pairs = extract_pairs(document, candidate, clock);
+                (!pairs.is_empty()).then_some((alternative, candidate, pairs))
+            })
+    else {
+        return facts;
+    };
+    on_match("facts", alternative, matched);
+    for (label, value) in pairs {
+        let key = match facts::canonical(&label, selectors.labels) {
+            Some(field) => field.as_str().to_string(),
+            None => label,
+        };
+        facts.entry(key).or_insert(value);
+    }
+    facts
+}
+
+fn extract_pairs(
+    document: &Html,
+    selector_rule: &Rule,
+    clock: &dyn Clock,
+) -> Vec<(String, String)> {
+    if !matches!(selector_rule.returns, RuleReturns::KeyValues) {
+        return extract_single(document, selector_rule, clock)
+            .into_iter()
+            .flat_map(|block| {
+                block
+                    .lines()
+                    .filter_map(|line| line.split_once(": "))
+                    .map(|(label, value)| (label.to_string(), value.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+    }
+    selected(document, selector_rule)
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|el| facts::key_values(el))
+        .filter_map(|(label, value)| {
+            transforms::apply_with_clock(&selector_rule.transforms, &value, clock)
+                .map(|value| (label, value))
+        })
+        .collect()
+}
+
+pub fn extract_tags(document: &Html, rule: &Rule) -> Vec<String> {
+    extract_from_rule(document, rule)
+        .map(|value| split_tags(&value))
+        .unwrap_or_default()
+}
+
+fn split_tags(value: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in value.split("\n\n").map(str::trim) {
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+pub fn extract_from_rule(document: &Html, selector_rule: &Rule) -> Option<String> {
+    extract_match(document, selector_rule, &SystemClock).map(|(_, _, value)| value)
+}
+
+pub(crate) fn extract_match<'r>(
+    document: &Html,
+    selector_rule: &'r Rule,
+    clock: &dyn Clock,
+) -> Option<(usize, &'r Rule, String)> {
+    selector_rule
+        .candidates()
+        .enumerate()
+        .find_map(|(alternative, candidate)| {
+            extract_single(document, candidate, clock)
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| (alternative, candidate, value))
+        })
+}
+
+fn selected<'a>(document: &'a Html, selector_rule: &Rule) -> Option<Vec<ElementRef<'a>>> {
+    let elements = selector_rule.selects.select(document).ok()?;
+    if elements.is_empty() {
+        return None;
+    }
+
+    let (start, end) = match selector_rule.n {
+        Some((s, e)) => (s, e.min(elements.len())),
+        None => (0, 1),
+    };
+    elements.get(start..end).map(<[_]>::to_vec)
+}
+
+fn extract_single(document: &Html, selector_rule: &Rule, clock: &dyn Clock) -> Option<String> {
+    let slice = selected(document, selector_rule)?;
+
+    let values: Vec<String> = match &selector_rule.returns {
+        RuleReturns::Text => slice
+            .iter()
+            .map(|el| el.text().collect::<Vec<_>>().join("\n").trim().to_string())
+            .collect(),
+        RuleReturns::Attribute(attr) => slice
+            .iter()
+            .filter_map(|el| el.value().attr(attr).map(|v| v.to_string()))
+            .collect(),
+        RuleReturns::Html => slice.iter().map(|el| el.html()).collect(),
+        RuleReturns::Markdown => slice
+            .iter()
+            .map(|el| markdown::element_to_markdown(*el))
+            .collect(),
+        RuleReturns::KeyValues => slice
+            .iter()
+            .map(|el| {
+                facts::key_values(*el)
+                    .iter()
+                    .map(|(label, value)| format!("{}: {}", label, value))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect(),
+    };
+
+    let values: Vec<String> = values
+        .iter()
+        .filter_map(|value| transforms::apply_with_clock(&selector_rule.transforms, value, clock))
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.join("\n\n"))
+}
@@ -1,9 +1,12 @@
-use crate::transforms::{hellowork_date, linkedin_id};
+use crate::error::ScraperError;
+use crate::transforms::Transform;
 use crate::Result;
 use crate::{
-    actions::{hellowork_board_action, wttj_board_action},
-    models::{Rule, RuleReturns, Selectors, UrlParameters},
+    actions::{hellowork_board_action, wttj_board_action, PageAction},
+    models::{JobField, Rule, RuleReturns, Selectors, Selects, UrlParameters},
 };
+use std::borrow::Cow;
+use url::Url;
 
 #[derive(Clone)]
 pub struct BoardConfig {
@@ -13,7 +16,74 @@ pub struct BoardConfig {
     pub job_path: &'static str,
     pub selectors: Selectors,
     pub url_params: UrlParameters,
-    pub board_page_action: Option<fn(&headless_chrome::Tab) -> Result<()>>,
+    pub board_page_action: Option<PageAction>,
+}
+
+impl BoardConfig {
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        if let Err(e) = Url::parse(self.base_url) {
+            problems.push(format!(
+                "base_url `{}` does not parse: {}",
+                self.base_url, e
+            ));
+        }
+        if !self.job_path.contains("{id}") && Url::parse(self.job_path).is_err() {
+            problems.push(format!(
+                "job_path `{}` neither contains `{{id}}` nor is an absolute URL",
+                self.job_path
+            ));
+        }
+        let sections = self
+            .selectors
+            .sections
+            .iter()
+            .map(|(name, rule)| (*name, rule));
+        let optional = self
+            .selectors
+            .optional_rules()
+            .into_iter()
+            .filter_map(|(name, rule)| Some((name, rule?)));
+        for (field, rules) in self
+            .selectors
+            .rules()
+            .into_iter()
+            .chain(sections)
+            .chain(optional)
+        {
+            for rule in rules.candidates() {
+                for transform in rule.transforms.iter() {
+                    if let Err(problem) = transform.validate() {
+                        problems.push(format!("{} for `{}`", problem, field));
+                    }
+                }
+                if let Err(e) = rule.selects.validate() {
+                    problems.push(format!(
+                        "selector `{}` for `{}` does not parse: {}",
+                        rule.selects, field, e
+                    ));
+                }
+                if let Some((start, end)) = rule.n {
+                    if start >= end {
+                        problems.push(format!(
+                            "range ({}, {}) for `{}` is empty or reversed",
+                            start, end, field
+                        ));
+                    }
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ScraperError::InvalidConfig {
+                board: self.name.to_string(),
+                problems,
+            })
+        }
+    }
 }
 
 pub const HELLOWORK: BoardConfig = BoardConfig {
@@ -23,54 +93,148 @@ pub const HELLOWORK: BoardConfig = BoardConfig {
     job_path: "/emplois/{id}.html",
     selectors: Selectors {
         card: Rule {
-            selects: "div[data-id-storage-target='item']",
+            selects: Selects::Css("div[data-id-storage-target='item']"),
             n: None,
             returns: RuleReturns::Html,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         id: Rule {
-            selects: "div[data-id-storage-target='item']",
+            selects: Selects::Css("div[data-id-storage-target='item']"),
             n: None,
             returns: RuleReturns::Attribute("data-id-storage-item-id"),
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         title: Rule {
-            selects: "h3.tw-inline p:first-of-type",
+            selects: Selects::Css("h3.tw-inline p:first-of-type"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         company: Rule {
-            selects: "h3.tw-inline p:last-of-type",
+            selects: Selects::Css("h3.tw-inline p:last-of-type"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         location: Rule {
-            selects: "div[data-cy='localisationCard']",
+            selects: Selects::Css("div[data-cy='localisationCard']"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         description: Rule {
-            selects: "div#offer-panel p",
-            n: Some((0, 3)),
-            returns: RuleReturns::Text,
-            transforms: None,
+            selects: Selects::Css("div#offer-panel"),
+            n: None,
+            returns: RuleReturns::Markdown,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         date_posted: Rule {
-            selects: "div[class='tw-typo-s tw-text-grey-500 tw-pl-1 tw-pt-1']",
+            selects: Selects::Css("div[class='tw-typo-s tw-text-grey-500 tw-pl-1 tw-pt-1']"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: Some(hellowork_date),
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
+        sections: &[
+            (
+                "missions",
+                Rule {
+                    selects: Selects::XPath(
+                        "//div[@id='offer-panel']//section[h2[contains(lower-case(.), 'mission')]]",
+                    ),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+            (
+                "profil",
+                Rule {
+                    selects: Selects::XPath(
+                        "//div[@id='offer-panel']//section[h2[contains(lower-case(.), 'profil')]]",
+                    ),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+            (
+                "avantages",
+                Rule {
+                    selects: Selects::XPath(
+                        "//div[@id='offer-panel']//section[h2[contains(lower-case(.), 'avantage')]]",
+                    ),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+        ],
+        apply_url: Some(Rule {
+            selects: Selects::Css("a[data-cy='applyButton']"),
+            n: None,
+            returns: RuleReturns::Attribute("href"),
+            transforms: Cow::Borrowed(&[Transform::UrlJoin {
+                base: Cow::Borrowed("https://www.hellowork.com/"),
+            }]),
+            alternatives: &[],
+        }),
+        company_url: Some(Rule {
+            selects: Selects::Css("a[href*='/fr-fr/entreprises/']"),
+            n: None,
+            returns: RuleReturns::Attribute("href"),
+            transforms: Cow::Borrowed(&[Transform::UrlJoin {
+                base: Cow::Borrowed("https://www.hellowork.com/"),
+            }]),
+            alternatives: &[],
+        }),
+        company_logo: Some(Rule {
+            selects: Selects::Css("img[data-cy='companyLogo']"),
+            n: None,
+            returns: RuleReturns::Attribute("src"),
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        company_size: None,
+        company_sector: None,
+        tags: Some(Rule {
+            selects: Selects::Css(
+                "div[data-cy='contractCard'], div[data-cy='remoteCard'], div[data-cy='salaryCard']",
+            ),
+            n: Some((0, 10)),
+            returns: RuleReturns::Text,
+            transforms: Cow::Borrowed(&[Transform::CollapseWhitespace]),
+            alternatives: &[],
+        }),
+        facts: Some(Rule {
+            selects: Selects::XPath("//section[h2[contains(., 'Informations')]]"),
+            n: None,
+            returns: RuleReturns::KeyValues,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        labels: &[
+            ("type de poste", JobField::Contract),
+            ("salaire brut", JobField::Salary),
+            ("experience requise", JobField::Experience),
+            ("niveau d'etudes requis", JobField::Education),
+        ],
     },
     url_params: UrlParameters {
         query: "k",
         location: "l",
         offset: "p",
     },
-    board_page_action: Some(hellowork_board_action),
+    board_page_action: Some(PageAction::Sync(hellowork_board_action)),
 };
 
 pub const LINKEDIN: BoardConfig = BoardConfig {
@@ -80,47 +244,114 @@ pub const LINKEDIN: BoardConfig = BoardConfig {
     job_path: "/jobs-guest/jobs/api/jobPosting/{id}",
     selectors: Selectors {
         card: Rule {
-            selects: "div.base-search-card",
+            selects: Selects::Css("div.base-search-card"),
             n: None,
             returns: RuleReturns::Html,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         id: Rule {
-            selects: "div.base-search-card",
+            selects: Selects::Css("div.base-search-card"),
             n: None,
             returns: RuleReturns::Attribute("data-entity-urn"),
-            transforms: Some(linkedin_id),
+            transforms: Cow::Borrowed(&[Transform::SplitNth {
+                separator: Cow::Borrowed(":"),
+                index: -1,
+            }]),
+            alternatives: &[],
         },
         title: Rule {
-            selects: "h3.base-search-card__title",
+            selects: Selects::Css("h3.base-search-card__title"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         company: Rule {
-            selects: "h4.base-search-card__subtitle a",
+            selects: Selects::Css("h4.base-search-card__subtitle a"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         location: Rule {
-            selects: "span.job-search-card__location",
+            selects: Selects::Css("span.job-search-card__location"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         description: Rule {
-            selects: "div.show-more-less-html__markup",
+            selects: Selects::Css("div.show-more-less-html__markup"),
             n: None,
-            returns: RuleReturns::Text,
-            transforms: None,
+            returns: RuleReturns::Markdown,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         date_posted: Rule {
-            selects: "time.job-search-card__listdate",
+            selects: Selects::Css("time.job-search-card__listdate"),
             n: None,
             returns: RuleReturns::Attribute("datetime"),
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
+        sections: &[(
+            "criteres",
+            Rule {
+                selects: Selects::Css("ul.description__job-criteria-list"),
+                n: None,
+                returns: RuleReturns::Markdown,
+                transforms: Cow::Borrowed(&[]),
+                alternatives: &[],
+            },
+        )],
+        apply_url: Some(Rule {
+            selects: Selects::Css("code#applyUrl"),
+            n: None,
+            returns: RuleReturns::Html,
+            transforms: Cow::Borrowed(&[Transform::RegexCapture {
+                pattern: Cow::Borrowed(r#""(https?://[^"]+)""#),
+                group: 1,
+            }]),
+            alternatives: &[],
+        }),
+        company_url: Some(Rule {
+            selects: Selects::Css("h4.base-search-card__subtitle a"),
+            n: None,
+            returns: RuleReturns::Attribute("href"),
+            transforms: Cow::Borrowed(&[Transform::SplitNth {
+                separator: Cow::Borrowed("?"),
+                index: 0,
+            }]),
+            alternatives: &[],
+        }),
+        company_logo: Some(Rule {
+            selects: Selects::Css("img.artdeco-entity-image"),
+            n: None,
+            returns: RuleReturns::Attribute("data-delayed-url"),
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        company_size: None,
+        company_sector: None,
+        tags: Some(Rule {
+            selects: Selects::Css("span.job-posting-benefits__text"),
+            n: Some((0, 10)),
+            returns: RuleReturns::Text,
+            transforms: Cow::Borrowed(&[Transform::CollapseWhitespace]),
+            alternatives: &[],
+        }),
+        facts: Some(Rule {
+            selects: Selects::Css("ul.description__job-criteria-list"),
+            n: None,
+            returns: RuleReturns::KeyValues,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        labels: &[
+            ("seniority level", JobField::Experience),
+            ("niveau hierarchique", JobField::Experience),
+        ],
     },
     url_params: UrlParameters {
         query: "keywords",
@@ -137,52 +368,151 @@ pub const WTTJ: BoardConfig = BoardConfig {
     job_path: "{id}",
     selectors: Selectors {
         card: Rule {
-            selects: "li[data-testid='search-results-list-item-wrapper']",
+            selects: Selects::Css("li[data-testid='search-results-list-item-wrapper']"),
             n: None,
             returns: RuleReturns::Html,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         id: Rule {
-            selects: "a",
+            selects: Selects::Css("a"),
             n: None,
             returns: RuleReturns::Attribute("href"),
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         title: Rule {
-            selects: "div[role='mark']",
+            selects: Selects::Css("div[role='mark']"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         company: Rule {
-            selects: "span.wui-text",
+            selects: Selects::Css("span.wui-text"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         location: Rule {
-            selects: "i[name='location'] + span > span",
+            selects: Selects::Css("i[name='location'] + span > span"),
             n: None,
             returns: RuleReturns::Text,
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         description: Rule {
-            selects: "div#the-position-section",
+            selects: Selects::Css("div#the-position-section"),
             n: None,
-            returns: RuleReturns::Text,
-            transforms: None,
+            returns: RuleReturns::Markdown,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
         date_posted: Rule {
-            selects: "time",
+            selects: Selects::Css("time"),
             n: None,
             returns: RuleReturns::Attribute("datetime"),
-            transforms: None,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
         },
+        sections: &[
+            (
+                "missions",
+                Rule {
+                    selects: Selects::Css("div[data-testid='job-section-description']"),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+            (
+                "profil",
+                Rule {
+                    selects: Selects::Css("div[data-testid='job-section-experience']"),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+            (
+                "process",
+                Rule {
+                    selects: Selects::Css("div[data-testid='job-section-process']"),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ),
+        ],
+        apply_url: Some(Rule {
+            selects: Selects::Css("a[data-testid='job_header-button-apply']"),
+            n: None,
+            returns: RuleReturns::Attribute("href"),
+            transforms: Cow::Borrowed(&[Transform::UrlJoin {
+                base: Cow::Borrowed("https://www.welcometothejungle.com/"),
+            }]),
+            alternatives: &[],
+        }),
+        company_url: Some(Rule {
+            selects: Selects::Css("a[href*='/companies/']:not([href*='/jobs/'])"),
+            n: None,
+            returns: RuleReturns::Attribute("href"),
+            transforms: Cow::Borrowed(&[Transform::UrlJoin {
+                base: Cow::Borrowed("https://www.welcometothejungle.com/"),
+            }]),
+            alternatives: &[],
+        }),
+        company_logo: Some(Rule {
+            selects: Selects::Css("img[alt][src*='logo']"),
+            n: None,
+            returns: RuleReturns::Attribute("src"),
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        company_size: Some(Rule {
+            selects: Selects::XPath(
+                "//div[@data-testid='job-company-tags']/*[contains(., 'salariés')]",
+            ),
+            n: None,
+            returns: RuleReturns::Text,
+            transforms: Cow::Borrowed(&[Transform::CollapseWhitespace]),
+            alternatives: &[],
+        }),
+        company_sector: Some(Rule {
+            selects: Selects::XPath("//div[@data-testid='job-company-tags']/*[1]"),
+            n: None,
+            returns: RuleReturns::Text,
+            transforms: Cow::Borrowed(&[Transform::CollapseWhitespace]),
+            alternatives: &[],
+        }),
+        tags: Some(Rule {
+            selects: Selects::Css("div[data-testid='job-card-tags'] > *"),
+            n: Some((0, 10)),
+            returns: RuleReturns::Text,
+            transforms: Cow::Borrowed(&[Transform::CollapseWhitespace]),
+            alternatives: &[],
+        }),
+        facts: Some(Rule {
+            selects: Selects::Css("div[data-testid='job-metadata-block']"),
+            n: None,
+            returns: RuleReturns::KeyValues,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[],
+        }),
+        labels: &[
+            ("etudes", JobField::Education),
+            ("experience", JobField::Experience),
+            ("salaire", JobField::Salary),
+        ],
     },
     url_params: UrlParameters {
         query: "query",
         location: "aroundQuery",
         offset: "page",
     },
-    board_page_action: Some(wttj_board_action),
+    board_page_action: Some(PageAction::Sync(wttj_board_action)),
 };
@@ -0,0 +1,295 @@
+use crate::text::fold;
+use chrono::{
+    DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DatePrecision {
+    Exact,
+    Day,
+    Estimated,
+    #[default]
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParsedDate {
+    pub datetime: DateTime<Utc>,
+    pub precision: DatePrecision,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostedDate {
+    pub datetime: Option<DateTime<Utc>>,
+    pub precision: DatePrecision,
+}
+
+impl PostedDate {
+    pub fn unknown() -> Self {
+        Self::default()
+    }
+
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.datetime.map(|d| d.date_naive())
+    }
+
+    pub fn is_known(&self) -> bool {
+        self.datetime.is_some()
+    }
+}
+
+impl From<ParsedDate> for PostedDate {
+    fn from(parsed: ParsedDate) -> Self {
+        Self {
+            datetime: Some(parsed.datetime),
+            precision: parsed.precision,
+        }
+    }
+}
+
+static RELATIVE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?x)
+        \b(\d+|une?|an?|one)\+?\s*
+        (minutes?|mins?|heures?|hours?|hrs?|h|jours?|days?|j|d|semaines?|weeks?|mois|months?|annees?|ans?|years?)\b",
+    )
+    .unwrap()
+});
+
+static FRENCH_DATE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(\d{1,2})(?:er)?\s+([a-z]+)\.?(?:\s+(\d{4}))?\b").unwrap());
+
+static ENGLISH_DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b([a-z]+)\.?\s+(\d{1,2})(?:st|nd|rd|th)?(?:,?\s+(\d{4}))?\b").unwrap()
+});
+
+static NUMERIC_DATE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(\d{1,2})[/.-](\d{1,2})[/.-](\d{4}|\d{2})\b").unwrap());
+
+static ISO_DAY: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").unwrap());
+
+const MONTHS: &[(&str, u32)] = &[
+    ("janvier", 1),
+    ("janv", 1),
+    ("january", 1),
+    ("jan", 1),
+    ("fevrier", 2),
+    ("fevr", 2),
+    ("fev", 2),
+    ("february", 2),
+    ("feb", 2),
+    ("mars", 3),
+    ("march", 3),
+    ("mar", 3),
+    ("avril", 4),
+    ("avr", 4),
+    ("april", 4),
+    ("apr", 4),
+    ("mai", 5),
+    ("may", 5),
+    ("juin", 6),
+    ("june", 6),
+    ("jun", 6),
+    ("juillet", 7),
+    ("juil", 7),
+    ("july", 7),
+    ("jul", 7),
+    ("aout", 8),
+    ("august", 8),
+    ("aug", 8),
+    ("septembre", 9),
+    ("september", 9),
+    ("sept", 9),
+    ("sep", 9),
+    ("octobre", 10),
+    ("october", 10),
+    ("oct", 10),
+    ("novembre", 11),
+    ("november", 11),
+    ("nov", 11),
+    ("decembre", 12),
+    ("december", 12),
+    ("dec", 12),
+];
+
+pub fn parse_date_label(text: &str, clock: &dyn Clock) -> Option<ParsedDate> {
+    let raw = text.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    if let Some(parsed) = parse_timestamp(raw) {
+        return Some(parsed);
+    }
+
+    let now = clock.now();
+    let text = fold(raw);
+
+    if text.contains("avant-hier") || text.contains("avant hier") {
+        return Some(day(now.date_naive() - Duration::days(2)));
+    }
+    if has_word(&text, "hier") || has_word(&text, "yesterday") {
+        return Some(day(now.date_naive() - Duration::days(1)));
+    }
+    if text.contains("aujourd'hui")
+        || text.contains("aujourdhui")
+        || has_word(&text, "today")
+        || text.contains("ce jour")
+    {
+        return Some(day(now.date_naive()));
+    }
+    if text.contains("just now") || text.contains("a l'instant") || text.contains("right now") {
+        return Some(estimated(now));
+    }
+
+    if let Some(date) = parse_calendar_date(&text, now.date_naive()) {
+        return Some(day(date));
+    }
+    parse_relative(&text, now)
+}
+
+fn parse_timestamp(text: &str) -> Option<ParsedDate> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Some(ParsedDate {
+            datetime: datetime.with_timezone(&Utc),
+            precision: DatePrecision::Exact,
+        });
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(ParsedDate {
+                datetime: Utc.from_utc_datetime(&datetime),
+                precision: DatePrecision::Exact,
+            });
+        }
+    }
+    None
+}
+
+fn parse_relative(text: &str, now: DateTime<Utc>) -> Option<ParsedDate> {
+    let captures = RELATIVE.captures(text)?;
+    let n: u32 = match &captures[1] {
+        "un" | "une" | "a" | "an" | "one" => 1,
+        digits => digits.parse().ok()?,
+    };
+    let unit = &captures[2];
+
+    let today = now.date_naive();
+    let datetime = if unit.starts_with("min") {
+        now.checked_sub_signed(Duration::try_minutes(n.into())?)?
+    } else if unit.starts_with('h') {
+        now.checked_sub_signed(Duration::try_hours(n.into())?)?
+    } else if unit.starts_with('j') || unit.starts_with('d') {
+        midnight(today.checked_sub_signed(Duration::try_days(n.into())?)?)
+    } else if unit.starts_with('s') || unit.starts_with('w') {
+        midnight(today.checked_sub_signed(Duration::try_weeks(n.into())?)?)
+    } else if unit == "mois" || unit.starts_with("month") {
+        midnight(today.checked_sub_months(Months::new(n))?)
+    } else {
+        midnight(today.checked_sub_months(Months::new(n.checked_mul(12)?))?)
+    };
+    Some(estimated(datetime))
+}
+
+fn parse_calendar_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    if let Some(captures) = ISO_DAY.captures(text) {
+        return NaiveDate::from_ymd_opt(
+            captures[1].parse().ok()?,
+            captures[2].parse().ok()?,
+            captures[3].parse().ok()?,
+        );
+    }
+    if let Some(captures) = NUMERIC_DATE.captures(text) {
+        let year: i32 = captures[3].parse().ok()?;
+        let year = if year < 100 { 2000 + year } else { year };
+        return NaiveDate::from_ymd_opt(year, captures[2].parse().ok()?, captures[1].parse().ok()?);
+    }
+    for captures in FRENCH_DATE.captures_iter(text) {
+        if let Some(month) = month(&captures[2]) {
+            let day_of_month = captures[1].parse().ok()?;
+            return with_year(
+                captures.get(3).map(|m| m.as_str()),
+                month,
+                day_of_month,
+                today,
+            );
+        }
+    }
+    for captures in ENGLISH_DATE.captures_iter(text) {
+        if let Some(month) = month(&captures[1]) {
+            let day_of_month = captures[2].parse().ok()?;
+            return with_year(
+                captures.get(3).map(|m| m.as_str()),
+                month,
+                day_of_month,
+                today,
+            );
+        }
+    }
+    None
+}
+
+fn with_year(year: Option<&str>, month: u32, day: u32, today: NaiveDate) -> Option<NaiveDate> {
+    match year {
+        Some(year) => NaiveDate::from_ymd_opt(year.parse().ok()?, month, day),
+        None => {
+            let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+            if date > today {
+                NaiveDate::from_ymd_opt(today.year() - 1, month, day)
+            } else {
+                Some(date)
+            }
+        }
+    }
+}
+
+fn has_word(text: &str, word: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric())
+        .any(|w| w == word)
+}
+
+fn month(name: &str) -> Option<u32> {
+    MONTHS.iter().find(|(n, _)| *n == name).map(|(_, m)| *m)
+}
+
+fn midnight(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN))
+}
+
+fn day(date: NaiveDate) -> ParsedDate {
+    ParsedDate {
+        datetime: midnight(date),
+        precision: DatePrecision::Day,
+    }
+}
+
+fn estimated(datetime: DateTime<Utc>) -> ParsedDate {
+    ParsedDate {
+        datetime,
+        precision: DatePrecision::Estimated,
+    }
+}
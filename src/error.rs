@@ -1,4 +1,5 @@
 use anyhow::Error as AnyhowError;
+use std::time::Duration;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, ScraperError>;
@@ -8,18 +9,117 @@ pub enum ScraperError {
     #[error("Failed to parse URL: {0}")]
     UrlParseError(#[from] url::ParseError),
 
-    #[error("Headless Chrome error: {0}")]
-    AnyhowError(#[from] AnyhowError),
-
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
-    #[error("Browser error: {0}")]
-    BrowserError(String),
+    #[error("[{board}] Failed to launch browser: {message}")]
+    Launch { board: String, message: String },
+
+    #[error("[{board}] Navigation to {url} failed: {message}")]
+    Navigation {
+        board: String,
+        url: String,
+        message: String,
+    },
+
+    #[error("[{board}] Timed out during {stage} on {url}")]
+    Timeout {
+        board: String,
+        url: String,
+        stage: String,
+    },
+
+    #[error("[{board}] Browser error during {stage} on {url}: {message}")]
+    Browser {
+        board: String,
+        url: String,
+        stage: String,
+        message: String,
+    },
+
+    #[error("[{board}] Selector `{selector}` for field `{field}` matched nothing on {url}")]
+    SelectorNotFound {
+        board: String,
+        url: String,
+        field: String,
+        selector: String,
+    },
+
+    #[error("[{board}] Blocked by an anti-bot or captcha page on {url}")]
+    Blocked { board: String, url: String },
+
+    #[error("[{board}] Rate limited on {url}{}", retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+    RateLimited {
+        board: String,
+        url: String,
+        retry_after: Option<Duration>,
+    },
+
+    #[error("[{board}] Invalid board configuration: {}", problems.join("; "))]
+    InvalidConfig {
+        board: String,
+        problems: Vec<String>,
+    },
+
+    #[error("[{board}] Extraction of `{field}` failed on {url}: {message}")]
+    ExtractionFailed {
+        board: String,
+        url: String,
+        field: String,
+        message: String,
+    },
+
+    #[cfg(feature = "search-index")]
+    #[error("Search index error: {0}")]
+    IndexError(String),
+}
+
+impl ScraperError {
+    pub(crate) fn from_browser(board: &str, url: &str, stage: &str, error: AnyhowError) -> Self {
+        if error
+            .downcast_ref::<headless_chrome::util::Timeout>()
+            .is_some()
+        {
+            Self::Timeout {
+                board: board.to_string(),
+                url: url.to_string(),
+                stage: stage.to_string(),
+            }
+        } else {
+            Self::Browser {
+                board: board.to_string(),
+                url: url.to_string(),
+                stage: stage.to_string(),
+                message: error.to_string(),
+            }
+        }
+    }
 
-    #[error("Element not found")]
-    ElementNotFound,
+    pub fn board(&self) -> Option<&str> {
+        match self {
+            Self::Launch { board, .. }
+            | Self::Navigation { board, .. }
+            | Self::Timeout { board, .. }
+            | Self::Browser { board, .. }
+            | Self::SelectorNotFound { board, .. }
+            | Self::Blocked { board, .. }
+            | Self::RateLimited { board, .. }
+            | Self::InvalidConfig { board, .. }
+            | Self::ExtractionFailed { board, .. } => Some(board),
+            _ => None,
+        }
+    }
 
-    #[error("Extraction failed: {0}")]
-    ExtractionFailed(String),
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Navigation { url, .. }
+            | Self::Timeout { url, .. }
+            | Self::Browser { url, .. }
+            | Self::SelectorNotFound { url, .. }
+            | Self::Blocked { url, .. }
+            | Self::RateLimited { url, .. }
+            | Self::ExtractionFailed { url, .. } => Some(url),
+            _ => None,
+        }
+    }
 }
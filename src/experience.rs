@@ -0,0 +1,249 @@
+use crate::text::fold;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExperienceLevel {
+    Internship,
+    Junior,
+    Mid,
+    Senior,
+    Lead,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YearsRange {
+    pub min: u32,
+    pub max: Option<u32>,
+}
+
+const ROLE: &str = r"(?:[\w-]+ )?(?:dev|developer|developpeur|developpeuse|engineer|ingenieur|ingenieure|architect|architecte|software|data|devops|sre|qa)";
+
+const KEYWORDS: &[(ExperienceLevel, &[&str])] = &[
+    (ExperienceLevel::Mid, &["mid-senior"]),
+    (
+        ExperienceLevel::Internship,
+        &[
+            "stage",
+            "stagiaire",
+            "internship",
+            "intern",
+            "alternance",
+            "alternant",
+            "alternante",
+            "apprenti",
+            "apprentie",
+            "apprentissage",
+        ],
+    ),
+    (
+        ExperienceLevel::Lead,
+        &[
+            "(?:lead|staff|principal) {ROLE}",
+            "(?:tech|team|technical|engineering|squad) lead",
+            "(?:engineering|software|development|developpement|it|tech|technical|data) manager",
+            "manager (?:technique|d'equipe)",
+            "head of",
+            "directeur",
+            "directrice",
+            "director",
+            "cto",
+        ],
+    ),
+    (
+        ExperienceLevel::Senior,
+        &[
+            "senior",
+            "sr",
+            "expert",
+            "experte",
+            "experimente",
+            "experimentee",
+        ],
+    ),
+    (
+        ExperienceLevel::Mid,
+        &[
+            "confirme",
+            "confirmee",
+            "intermediaire",
+            "mid",
+            "mid-level",
+            "associate",
+        ],
+    ),
+    (
+        ExperienceLevel::Junior,
+        &[
+            "junior",
+            "jr",
+            "debutant",
+            "debutante",
+            "entry level",
+            "graduate",
+            "jeune diplome",
+            "jeune diplomee",
+            "premier emploi",
+        ],
+    ),
+];
+
+const BOARD_LABELS: &[(&str, ExperienceLevel)] = &[
+    ("executive", ExperienceLevel::Lead),
+    ("lead", ExperienceLevel::Lead),
+    ("manager", ExperienceLevel::Lead),
+];
+
+const DESCRIPTION_PHRASES: &[(ExperienceLevel, &[&str])] = &[
+    (
+        ExperienceLevel::Internship,
+        &[
+            "(?:poste|offre) de (?:stage|stagiaire)",
+            "stage de fin d'etudes",
+            "internship position",
+            "contrat d'apprentissage",
+        ],
+    ),
+    (
+        ExperienceLevel::Lead,
+        &["poste de (?:tech )?lead", "lead position"],
+    ),
+    (
+        ExperienceLevel::Senior,
+        &[
+            "(?:profil|poste|candidat|candidate) (?:senior|experimente|experimentee|expert|experte)",
+            "senior (?:profile|position|candidate)",
+        ],
+    ),
+    (
+        ExperienceLevel::Mid,
+        &["(?:profil|poste|candidat|candidate) (?:confirme|confirmee)"],
+    ),
+    (
+        ExperienceLevel::Junior,
+        &[
+            "(?:profil|poste|candidat|candidate) (?:junior|debutant|debutante)",
+            "junior (?:profile|position|candidate)",
+            "debutants? acceptee?s?",
+            "jeunes? diplomee?s?",
+            "premier emploi",
+        ],
+    ),
+];
+
+static KEYWORD_PATTERNS: Lazy<Vec<(ExperienceLevel, Regex)>> = Lazy::new(|| patterns(KEYWORDS));
+
+static DESCRIPTION_PATTERNS: Lazy<Vec<(ExperienceLevel, Regex)>> =
+    Lazy::new(|| patterns(DESCRIPTION_PHRASES));
+
+static YEARS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:(?:au moins|minimum|min\.?|plus de|at least|over)\s*)?(\d{1,2})\s*(?:(?:a|-|–|to|et)\s*(\d{1,2})\s*)?(?:\+\s*)?(?:ans|an|annees|years?|yrs?)\b",
+    )
+    .unwrap()
+});
+
+static EXPERIENCE_CONTEXT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(?:experience|xp)\b").unwrap());
+
+const CONTEXT_WINDOW: usize = 40;
+
+pub fn infer(
+    field: Option<&str>,
+    title: &str,
+    description: &str,
+) -> (Option<ExperienceLevel>, Option<YearsRange>) {
+    let years = field
+        .and_then(|value| find_years(value, false))
+        .or_else(|| find_years(description, true));
+    let level = field
+        .and_then(level_from_label)
+        .or_else(|| level_from_label(title))
+        .or_else(|| years.map(level_from_years))
+        .or_else(|| level_from_description(description));
+    (level, years)
+}
+
+pub fn level_from_label(text: &str) -> Option<ExperienceLevel> {
+    let folded = fold(text.trim());
+    BOARD_LABELS
+        .iter()
+        .find(|(label, _)| *label == folded)
+        .map(|(_, level)| *level)
+        .or_else(|| first_match(&KEYWORD_PATTERNS, &folded))
+}
+
+pub fn level_from_description(text: &str) -> Option<ExperienceLevel> {
+    first_match(&DESCRIPTION_PATTERNS, &fold(text))
+}
+
+pub fn level_from_years(years: YearsRange) -> ExperienceLevel {
+    match years.min {
+        0 | 1 => ExperienceLevel::Junior,
+        2..=4 => ExperienceLevel::Mid,
+        _ => ExperienceLevel::Senior,
+    }
+}
+
+pub fn years_in_text(text: &str) -> Option<YearsRange> {
+    find_years(text, true)
+}
+
+fn patterns(table: &[(ExperienceLevel, &[&str])]) -> Vec<(ExperienceLevel, Regex)> {
+    table
+        .iter()
+        .map(|(level, phrases)| {
+            let phrases: Vec<String> = phrases.iter().map(|p| p.replace("{ROLE}", ROLE)).collect();
+            let pattern = format!(r"\b(?:{})\b", phrases.join("|"));
+            (*level, Regex::new(&pattern).unwrap())
+        })
+        .collect()
+}
+
+fn first_match(patterns: &[(ExperienceLevel, Regex)], folded: &str) -> Option<ExperienceLevel> {
+    patterns
+        .iter()
+        .find(|(_, pattern)| pattern.is_match(folded))
+        .map(|(level, _)| *level)
+}
+
+fn find_years(text: &str, needs_context: bool) -> Option<YearsRange> {
+    let folded = fold(text);
+    YEARS.captures_iter(&folded).find_map(|captures| {
+        let whole = captures.get(0)?;
+        if needs_context && !near_experience(&folded, whole.start(), whole.end()) {
+            return None;
+        }
+        let min: u32 = captures[1].parse().ok()?;
+        let max = match captures.get(2) {
+            Some(max) => Some(max.as_str().parse().ok()?),
+            None => None,
+        };
+        match max {
+            Some(max) if max < min => None,
+            max => Some(YearsRange { min, max }),
+        }
+    })
+}
+
+fn near_experience(text: &str, start: usize, end: usize) -> bool {
+    let before = floor_boundary(text, start.saturating_sub(CONTEXT_WINDOW));
+    let after = ceil_boundary(text, (end + CONTEXT_WINDOW).min(text.len()));
+    EXPERIENCE_CONTEXT.is_match(&text[before..after])
+}
+
+fn floor_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
@@ -0,0 +1,209 @@
+use crate::models::{JobField, Salary};
+use crate::text::fold;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::ElementRef;
+
+pub const COMMON_LABELS: &[(&str, JobField)] = &[
+    ("contrat", JobField::Contract),
+    ("type de contrat", JobField::Contract),
+    ("type d'emploi", JobField::Contract),
+    ("contract", JobField::Contract),
+    ("employment type", JobField::Contract),
+    ("salaire", JobField::Salary),
+    ("remuneration", JobField::Salary),
+    ("salary", JobField::Salary),
+    ("lieu", JobField::Location),
+    ("localisation", JobField::Location),
+    ("location", JobField::Location),
+    ("entreprise", JobField::Company),
+    ("societe", JobField::Company),
+    ("company", JobField::Company),
+    ("experience", JobField::Experience),
+    ("niveau d'experience", JobField::Experience),
+    ("experience level", JobField::Experience),
+    ("education", JobField::Education),
+    ("formation", JobField::Education),
+    ("niveau d'etudes", JobField::Education),
+    ("diplome", JobField::Education),
+    ("teletravail", JobField::Remote),
+    ("remote", JobField::Remote),
+    ("debut", JobField::StartDate),
+    ("date de debut", JobField::StartDate),
+    ("start date", JobField::StartDate),
+    ("taille", JobField::CompanySize),
+    ("taille de l'entreprise", JobField::CompanySize),
+    ("effectif", JobField::CompanySize),
+    ("company size", JobField::CompanySize),
+    ("secteur", JobField::CompanySector),
+    ("secteur d'activite", JobField::CompanySector),
+    ("secteurs", JobField::CompanySector),
+    ("industry", JobField::CompanySector),
+    ("industries", JobField::CompanySector),
+];
+
+static SALARY_AMOUNT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{1,3}(?:[ .]\d{3})+|\d+)(?:,(\d+))?\s*(k)?").unwrap());
+
+pub fn key_values(element: ElementRef) -> Vec<(String, String)> {
+    let terms: Vec<ElementRef> = descendants(element, "dt");
+    if !terms.is_empty() {
+        return terms
+            .into_iter()
+            .filter_map(|term| {
+                let definitions: Vec<String> = term
+                    .next_siblings()
+                    .filter_map(ElementRef::wrap)
+                    .take_while(|sibling| sibling.value().name() != "dt")
+                    .filter(|sibling| sibling.value().name() == "dd")
+                    .map(text_of)
+                    .collect();
+                pair(&text_of(term), &definitions.join(", "))
+            })
+            .collect();
+    }
+
+    let rows = descendants(element, "tr");
+    if !rows.is_empty() {
+        return rows.into_iter().filter_map(item_pair).collect();
+    }
+
+    let items = descendants(element, "li");
+    if !items.is_empty() {
+        return items.into_iter().filter_map(item_pair).collect();
+    }
+
+    let children: Vec<(String, String)> = element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter_map(item_pair)
+        .collect();
+    if !children.is_empty() {
+        return children;
+    }
+    item_pair(element).into_iter().collect()
+}
+
+pub fn canonical(label: &str, aliases: &[(&str, JobField)]) -> Option<JobField> {
+    let label = fold(label);
+    let label = label.trim();
+    aliases
+        .iter()
+        .chain(COMMON_LABELS)
+        .find(|(alias, _)| fold(alias) == label)
+        .map(|(_, field)| *field)
+}
+
+pub fn parse_salary(text: &str) -> Option<Salary> {
+    let folded = fold(text);
+    let amounts: Vec<(f64, bool)> = SALARY_AMOUNT
+        .captures_iter(&folded)
+        .filter_map(|captures| {
+            let whole: String = captures[1].chars().filter(char::is_ascii_digit).collect();
+            let amount: f64 = match captures.get(2) {
+                Some(decimals) => format!("{}.{}", whole, decimals.as_str()).parse().ok()?,
+                None => whole.parse().ok()?,
+            };
+            Some((amount, captures.get(3).is_some()))
+        })
+        .collect();
+    let thousands = amounts.iter().any(|(_, k)| *k);
+    let amounts: Vec<f64> = amounts
+        .into_iter()
+        .map(|(amount, k)| {
+            if k || (thousands && amount < 1000.0) {
+                amount * 1000.0
+            } else {
+                amount
+            }
+        })
+        .collect();
+    let min = *amounts.first()?;
+    let max = amounts.get(1).copied().unwrap_or(min);
+
+    let currency = if folded.contains('€') || folded.contains("eur") {
+        Some("EUR")
+    } else if folded.contains('£') || folded.contains("gbp") {
+        Some("GBP")
+    } else if folded.contains("chf") {
+        Some("CHF")
+    } else if folded.contains('$') || folded.contains("usd") {
+        Some("USD")
+    } else {
+        None
+    };
+    let period = [
+        ("heure", "HOUR"),
+        ("hour", "HOUR"),
+        ("jour", "DAY"),
+        ("day", "DAY"),
+        ("mois", "MONTH"),
+        ("mensuel", "MONTH"),
+        ("month", "MONTH"),
+        ("an", "YEAR"),
+        ("annuel", "YEAR"),
+        ("year", "YEAR"),
+        ("k", "YEAR"),
+    ]
+    .iter()
+    .find(|(word, _)| {
+        folded
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|token| token == *word || token.trim_start_matches(char::is_numeric) == *word)
+    })
+    .map(|(_, period)| period.to_string());
+
+    Some(Salary {
+        min: Some(min),
+        max: Some(max),
+        currency: currency.map(str::to_string),
+        period,
+    })
+}
+
+fn item_pair(item: ElementRef) -> Option<(String, String)> {
+    let text = text_of(item);
+    let parts: Vec<String> = item
+        .children()
+        .filter_map(ElementRef::wrap)
+        .map(text_of)
+        .filter(|part| !part.is_empty())
+        .collect();
+    match parts.as_slice() {
+        [label, rest @ ..] if !rest.is_empty() => pair(label, &rest.join(" ")),
+        [label] if text.len() > label.len() && text.starts_with(label.as_str()) => {
+            pair(label, &text[label.len()..])
+        }
+        _ => {
+            let (label, value) = text.split_once(':')?;
+            pair(label, value)
+        }
+    }
+}
+
+fn pair(label: &str, value: &str) -> Option<(String, String)> {
+    let label = label.trim().trim_end_matches(':').trim();
+    let value = value.trim().trim_start_matches(':').trim();
+    if label.is_empty() || value.is_empty() {
+        return None;
+    }
+    Some((label.to_string(), value.to_string()))
+}
+
+fn descendants<'a>(element: ElementRef<'a>, name: &str) -> Vec<ElementRef<'a>> {
+    element
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|descendant| descendant.value().name() == name)
+        .collect()
+}
+
+fn text_of(element: ElementRef) -> String {
+    element
+        .text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
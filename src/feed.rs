@@ -0,0 +1,170 @@
+use crate::models::Job;
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+#[derive(Clone, Debug)]
+pub struct FeedInfo {
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub description: String,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+impl Default for FeedInfo {
+    fn default() -> Self {
+        Self {
+            id: "urn:scraptain:feed".to_string(),
+            title: "scraptain".to_string(),
+            link: String::new(),
+            description: "Job postings collected by scraptain".to_string(),
+            updated: None,
+        }
+    }
+}
+
+pub fn to_feed(jobs: &[Job], info: &FeedInfo, format: FeedFormat) -> String {
+    match format {
+        FeedFormat::Atom => to_atom(jobs, info),
+        FeedFormat::Rss => to_rss(jobs, info),
+    }
+}
+
+pub fn to_atom(jobs: &[Job], info: &FeedInfo) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    push_element(&mut xml, 1, "id", &info.id);
+    push_element(&mut xml, 1, "title", &info.title);
+    if !info.description.is_empty() {
+        push_element(&mut xml, 1, "subtitle", &info.description);
+    }
+    let feed_updated = feed_updated(jobs, info);
+    push_element(&mut xml, 1, "updated", &feed_updated.to_rfc3339());
+    if !info.link.is_empty() {
+        xml.push_str(&format!("  <link href=\"{}\"/>\n", escape(&info.link)));
+    }
+    push_element(&mut xml, 1, "generator", "scraptain");
+
+    for job in jobs {
+        xml.push_str("  <entry>\n");
+        push_element(&mut xml, 2, "id", &entry_id(job));
+        push_element(&mut xml, 2, "title", &job.title);
+        let updated = job.date_posted.datetime.unwrap_or(feed_updated);
+        push_element(&mut xml, 2, "updated", &updated.to_rfc3339());
+        if let Some(published) = job.date_posted.datetime {
+            push_element(&mut xml, 2, "published", &published.to_rfc3339());
+        }
+        xml.push_str("    <author>\n");
+        push_element(&mut xml, 3, "name", author(job));
+        xml.push_str("    </author>\n");
+        if !job.url.is_empty() {
+            xml.push_str(&format!(
+                "    <link rel=\"alternate\" href=\"{}\"/>\n",
+                escape(&job.url)
+            ));
+        }
+        xml.push_str(&format!(
+            "    <category term=\"{}\"/>\n",
+            escape(&job.source)
+        ));
+        push_element(&mut xml, 2, "summary", &summary(job));
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+pub fn to_rss(jobs: &[Job], info: &FeedInfo) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\">\n");
+    xml.push_str("  <channel>\n");
+    push_element(&mut xml, 2, "title", &info.title);
+    if !info.link.is_empty() {
+        push_element(&mut xml, 2, "link", &info.link);
+    }
+    push_element(&mut xml, 2, "description", &info.description);
+    push_element(
+        &mut xml,
+        2,
+        "lastBuildDate",
+        &feed_updated(jobs, info).to_rfc2822(),
+    );
+    push_element(&mut xml, 2, "generator", "scraptain");
+
+    for job in jobs {
+        xml.push_str("    <item>\n");
+        push_element(&mut xml, 3, "title", &job.title);
+        if !job.url.is_empty() {
+            push_element(&mut xml, 3, "link", &job.url);
+        }
+        xml.push_str(&format!(
+            "      <guid isPermaLink=\"false\">{}</guid>\n",
+            escape(&entry_id(job))
+        ));
+        if let Some(published) = job.date_posted.datetime {
+            push_element(&mut xml, 3, "pubDate", &published.to_rfc2822());
+        }
+        push_element(&mut xml, 3, "category", &job.source);
+        push_element(&mut xml, 3, "description", &summary(job));
+        xml.push_str("    </item>\n");
+    }
+
+    xml.push_str("  </channel>\n");
+    xml.push_str("</rss>\n");
+    xml
+}
+
+fn entry_id(job: &Job) -> String {
+    format!("urn:scraptain:{}:{}", job.source, job.id)
+}
+
+fn feed_updated(jobs: &[Job], info: &FeedInfo) -> DateTime<Utc> {
+    info.updated
+        .or_else(|| jobs.iter().filter_map(|job| job.date_posted.datetime).max())
+        .unwrap_or(DateTime::UNIX_EPOCH)
+}
+
+fn author(job: &Job) -> &str {
+    if job.company.is_empty() {
+        &job.source
+    } else {
+        &job.company
+    }
+}
+
+fn summary(job: &Job) -> String {
+    if job.location.is_empty() {
+        job.description.clone()
+    } else if job.description.is_empty() {
+        job.location.clone()
+    } else {
+        format!("{}\n\n{}", job.location, job.description)
+    }
+}
+
+fn push_element(xml: &mut String, depth: usize, name: &str, value: &str) {
+    xml.push_str(&"  ".repeat(depth));
+    xml.push_str(&format!("<{name}>{}</{name}>\n", escape(value)));
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if (c as u32) < 0x20 && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,109 @@
+use crate::board::extract_from_rule;
+use crate::error::{Result, ScraperError};
+use crate::models::Selectors;
+use chrono::Utc;
+use scraper::Html;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug)]
+pub struct HealthCheckOptions {
+    pub sample_size: usize,
+    pub threshold: f64,
+    pub samples_dir: PathBuf,
+}
+
+impl Default for HealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            sample_size: 5,
+            threshold: 0.8,
+            samples_dir: std::env::temp_dir().join("scraptain-health"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldHealth {
+    pub field: &'static str,
+    pub selector: String,
+    pub matched: usize,
+    pub total: usize,
+    pub sample: Option<PathBuf>,
+}
+
+impl FieldHealth {
+    pub fn match_rate(&self) -> Option<f64> {
+        if self.total == 0 {
+            None
+        } else {
+            Some(self.matched as f64 / self.total as f64)
+        }
+    }
+
+    pub fn is_drifted(&self, threshold: f64) -> bool {
+        self.match_rate().is_some_and(|rate| rate < threshold)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BoardHealth {
+    pub board: String,
+    pub url: String,
+    pub fields: Vec<FieldHealth>,
+}
+
+impl BoardHealth {
+    pub fn drifted(&self, threshold: f64) -> Vec<&FieldHealth> {
+        self.fields
+            .iter()
+            .filter(|f| f.is_drifted(threshold))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HealthReport {
+    pub threshold: f64,
+    pub boards: Vec<BoardHealth>,
+    pub errors: Vec<ScraperError>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.errors.is_empty()
+            && self
+                .boards
+                .iter()
+                .all(|b| b.drifted(self.threshold).is_empty())
+    }
+}
+
+pub fn measure_cards(selectors: &Selectors, cards: &[Html]) -> Vec<FieldHealth> {
+    selectors
+        .rules()
+        .into_iter()
+        .filter(|(field, _)| !matches!(*field, "card" | "description"))
+        .map(|(field, rule)| FieldHealth {
+            field,
+            selector: rule.selects.to_string(),
+            matched: cards
+                .iter()
+                .filter(|card| extract_from_rule(card, rule).is_some_and(|v| !v.trim().is_empty()))
+                .count(),
+            total: cards.len(),
+            sample: None,
+        })
+        .collect()
+}
+
+pub(crate) fn save_sample(dir: &Path, board: &str, page: &str, html: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "{}-{}-{}.html",
+        board,
+        page,
+        Utc::now().format("%Y%m%dT%H%M%S")
+    ));
+    std::fs::write(&path, html)?;
+    Ok(path)
+}
@@ -0,0 +1,289 @@
+use crate::error::{Result, ScraperError};
+use crate::models::Job;
+use chrono::{NaiveDate, NaiveTime};
+use std::ops::Bound;
+use std::path::Path;
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, RangeQuery, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, INDEXED, STORED,
+};
+use tantivy::tokenizer::{
+    AsciiFoldingFilter, Language, LowerCaser, RawTokenizer, RemoveLongFilter, SimpleTokenizer,
+    Stemmer, TextAnalyzer,
+};
+use tantivy::{DateTime, Index, IndexReader, ReloadPolicy, TantivyDocument, Term};
+
+const FRENCH_TOKENIZER: &str = "scraptain_fr";
+const KEYWORD_TOKENIZER: &str = "scraptain_keyword";
+const WRITER_MEMORY: usize = 50_000_000;
+
+#[derive(Clone, Debug)]
+pub struct IndexQuery {
+    pub text: String,
+    pub sources: Vec<String>,
+    pub contracts: Vec<String>,
+    pub posted_after: Option<NaiveDate>,
+    pub posted_before: Option<NaiveDate>,
+    pub limit: usize,
+}
+
+impl Default for IndexQuery {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            sources: Vec::new(),
+            contracts: Vec::new(),
+            posted_after: None,
+            posted_before: None,
+            limit: 20,
+        }
+    }
+}
+
+struct Fields {
+    key: Field,
+    title: Field,
+    company: Field,
+    location: Field,
+    description: Field,
+    source: Field,
+    contract: Field,
+    date_posted: Field,
+    job: Field,
+}
+
+pub struct JobIndex {
+    index: Index,
+    reader: IndexReader,
+    fields: Fields,
+}
+
+impl JobIndex {
+    pub fn open_or_create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        std::fs::create_dir_all(path.as_ref())?;
+        let directory = MmapDirectory::open(path.as_ref()).map_err(index_error)?;
+        let index = Index::open_or_create(directory, Self::schema()).map_err(index_error)?;
+        Self::from_index(index)
+    }
+
+    pub fn in_memory() -> Result<Self> {
+        Self::from_index(Index::create_in_ram(Self::schema()))
+    }
+
+    pub fn add_jobs(&mut self, jobs: &[Job]) -> Result<()> {
+        let mut writer = self
+            .index
+            .writer::<TantivyDocument>(WRITER_MEMORY)
+            .map_err(index_error)?;
+        let fields = &self.fields;
+
+        for job in jobs {
+            let key = format!("{}:{}", job.source, job.id);
+            writer.delete_term(Term::from_field_text(fields.key, &key));
+
+            let mut doc = TantivyDocument::default();
+            doc.add_text(fields.key, &key);
+            doc.add_text(fields.title, &job.title);
+            doc.add_text(fields.company, &job.company);
+            doc.add_text(fields.location, &job.location);
+            doc.add_text(fields.description, &job.description);
+            doc.add_text(fields.source, &job.source);
+            if let Some(contract) = &job.contract {
+                doc.add_text(fields.contract, contract);
+            }
+            if let Some(date) = job.date_posted.date() {
+                doc.add_date(fields.date_posted, to_index_date(date));
+            }
+            let stored = serde_json::to_string(job)
+                .map_err(|e| ScraperError::IndexError(format!("Failed to store job: {}", e)))?;
+            doc.add_text(fields.job, stored);
+            writer.add_document(doc).map_err(index_error)?;
+        }
+
+        writer.commit().map_err(index_error)?;
+        self.reader.reload().map_err(index_error)?;
+        Ok(())
+    }
+
+    pub fn search(&self, query: &IndexQuery) -> Result<Vec<Job>> {
+        let fields = &self.fields;
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+        if query.text.trim().is_empty() {
+            clauses.push((Occur::Must, Box::new(AllQuery)));
+        } else {
+            let parser = QueryParser::for_index(
+                &self.index,
+                vec![
+                    fields.title,
+                    fields.company,
+                    fields.location,
+                    fields.description,
+                ],
+            );
+            let (text_query, _) = parser.parse_query_lenient(&query.text);
+            clauses.push((Occur::Must, text_query));
+        }
+
+        if !query.sources.is_empty() {
+            clauses.push((Occur::Must, self.any_of(fields.source, &query.sources)));
+        }
+        if !query.contracts.is_empty() {
+            clauses.push((Occur::Must, self.any_of(fields.contract, &query.contracts)));
+        }
+        if query.posted_after.is_some() || query.posted_before.is_some() {
+            let lower = query
+                .posted_after
+                .map(|d| Bound::Included(to_index_date(d)))
+                .unwrap_or(Bound::Unbounded);
+            let upper = query
+                .posted_before
+                .map(|d| Bound::Included(to_index_date(d)))
+                .unwrap_or(Bound::Unbounded);
+            clauses.push((
+                Occur::Must,
+                Box::new(RangeQuery::new_date_bounds(
+                    "date_posted".to_string(),
+                    lower,
+                    upper,
+                )),
+            ));
+        }
+
+        let searcher = self.reader.searcher();
+        let top_docs = searcher
+            .search(
+                &BooleanQuery::new(clauses),
+                &TopDocs::with_limit(query.limit.max(1)),
+            )
+            .map_err(index_error)?;
+
+        let mut jobs = Vec::with_capacity(top_docs.len());
+        for (_, address) in top_docs {
+            let doc: TantivyDocument = searcher.doc(address).map_err(index_error)?;
+            let stored = doc
+                .get_first(fields.job)
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ScraperError::IndexError("Stored job is missing".to_string()))?;
+            let job = serde_json::from_str(stored)
+                .map_err(|e| ScraperError::IndexError(format!("Failed to read job: {}", e)))?;
+            jobs.push(job);
+        }
+        Ok(jobs)
+    }
+
+    pub fn len(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn from_index(index: Index) -> Result<Self> {
+        index.tokenizers().register(
+            FRENCH_TOKENIZER,
+            TextAnalyzer::builder(SimpleTokenizer::default())
+                .filter(RemoveLongFilter::limit(40))
+                .filter(LowerCaser)
+                .filter(AsciiFoldingFilter)
+                .filter(Stemmer::new(Language::French))
+                .build(),
+        );
+        index.tokenizers().register(
+            KEYWORD_TOKENIZER,
+            TextAnalyzer::builder(RawTokenizer::default())
+                .filter(LowerCaser)
+                .filter(AsciiFoldingFilter)
+                .build(),
+        );
+
+        let schema = index.schema();
+        let field = |name: &str| schema.get_field(name).map_err(index_error);
+        let fields = Fields {
+            key: field("key")?,
+            title: field("title")?,
+            company: field("company")?,
+            location: field("location")?,
+            description: field("description")?,
+            source: field("source")?,
+            contract: field("contract")?,
+            date_posted: field("date_posted")?,
+            job: field("job")?,
+        };
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(index_error)?;
+
+        Ok(Self {
+            index,
+            reader,
+            fields,
+        })
+    }
+
+    fn schema() -> Schema {
+        let text = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(FRENCH_TOKENIZER)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        );
+        let keyword = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(KEYWORD_TOKENIZER)
+                .set_index_option(IndexRecordOption::Basic),
+        );
+        let raw = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("raw")
+                .set_index_option(IndexRecordOption::Basic),
+        );
+
+        let mut builder = Schema::builder();
+        builder.add_text_field("key", raw);
+        builder.add_text_field("title", text.clone());
+        builder.add_text_field("company", text.clone());
+        builder.add_text_field("location", text.clone());
+        builder.add_text_field("description", text);
+        builder.add_text_field("source", keyword.clone());
+        builder.add_text_field("contract", keyword);
+        builder.add_date_field("date_posted", INDEXED);
+        builder.add_text_field("job", STORED);
+        builder.build()
+    }
+
+    fn any_of(&self, field: Field, values: &[String]) -> Box<dyn Query> {
+        let mut analyzer = self
+            .index
+            .tokenizers()
+            .get(KEYWORD_TOKENIZER)
+            .expect("keyword tokenizer is registered");
+        let clauses = values
+            .iter()
+            .map(|value| {
+                let mut token = String::new();
+                analyzer
+                    .token_stream(value)
+                    .process(&mut |t| token = t.text.clone());
+                let query: Box<dyn Query> = Box::new(TermQuery::new(
+                    Term::from_field_text(field, &token),
+                    IndexRecordOption::Basic,
+                ));
+                (Occur::Should, query)
+            })
+            .collect();
+        Box::new(BooleanQuery::new(clauses))
+    }
+}
+
+fn to_index_date(date: NaiveDate) -> DateTime {
+    DateTime::from_timestamp_secs(date.and_time(NaiveTime::MIN).and_utc().timestamp())
+}
+
+fn index_error<E: std::fmt::Display>(e: E) -> ScraperError {
+    ScraperError::IndexError(e.to_string())
+}
@@ -0,0 +1,180 @@
+use crate::dates::{parse_date_label, Clock, ParsedDate};
+use crate::markdown::html_to_markdown;
+use crate::models::Salary;
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobPosting {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub company: Option<String>,
+    pub company_url: Option<String>,
+    pub company_logo: Option<String>,
+    pub location: Option<String>,
+    pub date_posted: Option<ParsedDate>,
+    pub valid_through: Option<ParsedDate>,
+    pub employment_type: Option<String>,
+    pub experience: Option<String>,
+    pub salary: Option<Salary>,
+}
+
+pub fn extract_job_posting(document: &Html, clock: &dyn Clock) -> Option<JobPosting> {
+    let selector = Selector::parse("script[type='application/ld+json']").ok()?;
+    document
+        .select(&selector)
+        .filter_map(|script| {
+            let raw: String = script.text().collect();
+            serde_json::from_str::<Value>(raw.trim()).ok()
+        })
+        .find_map(|value| find_posting(&value).map(|posting| parse_posting(posting, clock)))
+}
+
+fn find_posting(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(items) => items.iter().find_map(find_posting),
+        Value::Object(object) => {
+            if is_type(value, "JobPosting") {
+                return Some(value);
+            }
+            object.get("@graph").and_then(find_posting)
+        }
+        _ => None,
+    }
+}
+
+fn is_type(value: &Value, name: &str) -> bool {
+    match value.get("@type") {
+        Some(Value::String(t)) => t == name,
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some(name)),
+        _ => false,
+    }
+}
+
+fn parse_posting(posting: &Value, clock: &dyn Clock) -> JobPosting {
+    let date = |key: &str| text(posting.get(key)).and_then(|d| parse_date_label(&d, clock));
+    let organization = posting.get("hiringOrganization").filter(|o| o.is_object());
+    JobPosting {
+        title: text(posting.get("title")),
+        description: text(posting.get("description")).map(|d| description(&d)),
+        company: text(posting.get("hiringOrganization")),
+        company_url: organization
+            .and_then(|o| o.get("sameAs").or_else(|| o.get("url")))
+            .and_then(link),
+        company_logo: organization.and_then(|o| o.get("logo")).and_then(link),
+        location: location(posting.get("jobLocation")),
+        date_posted: date("datePosted"),
+        valid_through: date("validThrough"),
+        employment_type: employment_type(posting.get("employmentType")),
+        salary: posting.get("baseSalary").and_then(salary),
+        experience: posting.get("experienceRequirements").and_then(experience),
+    }
+}
+
+fn text(value: Option<&Value>) -> Option<String> {
+    let text = match value? {
+        Value::String(s) => s.trim().to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::Object(object) => return text(object.get("name")),
+        Value::Array(items) => return items.iter().find_map(|item| text(Some(item))),
+        _ => return None,
+    };
+    Some(text).filter(|t| !t.is_empty())
+}
+
+fn link(value: &Value) -> Option<String> {
+    match value {
+        Value::String(url) => Some(url.trim().to_string()).filter(|url| !url.is_empty()),
+        Value::Object(object) => object
+            .get("url")
+            .or_else(|| object.get("contentUrl"))
+            .and_then(link),
+        Value::Array(items) => items.iter().find_map(link),
+        _ => None,
+    }
+}
+
+fn number(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s
+            .replace([' ', '\u{a0}'], "")
+            .replace(',', ".")
+            .parse()
+            .ok(),
+        _ => None,
+    }
+}
+
+fn description(raw: &str) -> String {
+    if raw.contains("&lt;") {
+        let decoded: String = Html::parse_fragment(raw).root_element().text().collect();
+        html_to_markdown(&decoded)
+    } else {
+        html_to_markdown(raw)
+    }
+}
+
+fn location(value: Option<&Value>) -> Option<String> {
+    let place = match value? {
+        Value::Array(places) => places.first()?,
+        place => place,
+    };
+    let address = match place.get("address") {
+        Some(Value::String(address)) => return Some(address.trim().to_string()),
+        Some(address) => address,
+        None => return text(Some(place)),
+    };
+    let parts: Vec<String> = ["addressLocality", "postalCode", "addressRegion"]
+        .iter()
+        .filter_map(|key| text(address.get(*key)))
+        .collect();
+    if parts.is_empty() {
+        text(address.get("addressCountry"))
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
+fn employment_type(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::Array(types) => {
+            let types: Vec<String> = types.iter().filter_map(|t| text(Some(t))).collect();
+            Some(types.join(", ")).filter(|t| !t.is_empty())
+        }
+        value => text(Some(value)),
+    }
+}
+
+fn experience(value: &Value) -> Option<String> {
+    match number(value.get("monthsOfExperience")) {
+        Some(months) => Some(format!("{} years", (months / 12.0).round())),
+        None => text(value.get("description")).or_else(|| text(Some(value))),
+    }
+}
+
+fn salary(value: &Value) -> Option<Salary> {
+    let amount = value.get("value").unwrap_or(value);
+    let (min, max) = match amount {
+        Value::Object(_) => {
+            let exact = number(amount.get("value"));
+            (
+                number(amount.get("minValue")).or(exact),
+                number(amount.get("maxValue")).or(exact),
+            )
+        }
+        amount => {
+            let exact = number(Some(amount));
+            (exact, exact)
+        }
+    };
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(Salary {
+        min,
+        max,
+        currency: text(value.get("currency")),
+        period: text(amount.get("unitText")).or_else(|| text(value.get("unitText"))),
+    })
+}
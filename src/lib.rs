@@ -1,10 +1,30 @@
 pub mod actions;
+mod artifacts;
 pub mod board;
 pub mod constants;
+pub mod dates;
 pub mod error;
+pub mod experience;
+pub mod facts;
+pub mod feed;
+pub mod health;
+#[cfg(feature = "search-index")]
+pub mod index;
+pub mod jsonld;
+pub mod location;
+pub mod markdown;
 pub mod models;
+pub mod scoring;
+pub mod skills;
+pub mod text;
 pub mod transforms;
+pub mod xpath;
 
 pub use board::BoardScraper;
 pub use error::{Result, ScraperError};
-pub use models::{Board, Job, JobSearchParams};
+pub use experience::ExperienceLevel;
+pub use feed::{FeedFormat, FeedInfo};
+pub use location::Location;
+pub use models::{
+    Board, CardCheck, CareerSite, Job, JobSearchParams, KeywordField, Radius, Salary, SearchReport,
+};
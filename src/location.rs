@@ -0,0 +1,433 @@
+use crate::text::fold;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+const REGIONS_CSV: &str = include_str!("../data/geo/regions.csv");
+const DEPARTEMENTS_CSV: &str = include_str!("../data/geo/departements.csv");
+const COMMUNES_CSV: &str = include_str!("../data/geo/communes.csv");
+const FOREIGN_CSV: &str = include_str!("../data/geo/foreign.csv");
+
+const ALIASES: &[(&str, &str, &str)] = &[
+    ("La Défense", "Puteaux", "92"),
+    ("Paris La Défense", "Puteaux", "92"),
+    ("Sophia Antipolis", "Valbonne", "06"),
+    ("Saint-Quentin-en-Yvelines", "Guyancourt", "78"),
+    ("Marne-la-Vallée", "Champs-sur-Marne", "77"),
+    ("Paris-Saclay", "Saclay", "91"),
+    ("Cergy-Pontoise", "Cergy", "95"),
+];
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub city: Option<String>,
+    pub postcode: Option<String>,
+    pub department: Option<String>,
+    pub department_code: Option<String>,
+    pub region: Option<String>,
+    pub country: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+}
+
+impl Location {
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.lat?, self.lon?))
+    }
+}
+
+struct Region {
+    code: String,
+    name: String,
+}
+
+struct Department {
+    code: String,
+    name: String,
+    region: String,
+}
+
+#[derive(Clone)]
+struct Commune {
+    name: String,
+    postcode: String,
+    department: String,
+    lat: f64,
+    lon: f64,
+}
+
+struct ForeignCity {
+    name: String,
+    country: String,
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Place {
+    Communes(usize),
+    Foreign(usize),
+    Department(usize),
+    Region(usize),
+    Country,
+}
+
+struct Geography {
+    regions: Vec<Region>,
+    departments: Vec<Department>,
+    communes: Vec<Commune>,
+    extra: Vec<Commune>,
+    foreign: Vec<ForeignCity>,
+    commune_groups: Vec<Vec<usize>>,
+    names: Vec<(String, Place)>,
+}
+
+static GEOGRAPHY: Lazy<RwLock<Geography>> = Lazy::new(|| RwLock::new(Geography::load(Vec::new())));
+
+static POSTCODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b([0-9]{5})\b").unwrap());
+
+static ARRONDISSEMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(paris|lyon|marseille) ([0-9]{1,2})(?: ?(e|er|eme|ieme|arr|arrondissement))?\b")
+        .unwrap()
+});
+
+static SPACED_ARRONDISSEMENT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(paris|lyon|marseille)[ \t]+([0-9]{1,2})\b").unwrap());
+
+static DEPARTMENT_CODE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b([0-9]{2}|2a|2b|97[0-9])\b").unwrap());
+
+pub fn normalize(raw: &str) -> Option<Location> {
+    GEOGRAPHY.read().ok()?.resolve(raw)
+}
+
+pub fn load_communes(csv: &str) -> Result<usize, String> {
+    let extra = parse_communes(csv)?;
+    let mut geography = GEOGRAPHY.write().map_err(|e| e.to_string())?;
+    let mut communes: Vec<Commune> = std::mem::take(&mut geography.extra);
+    for commune in extra {
+        let duplicate = |c: &Commune| c.name == commune.name && c.department == commune.department;
+        if !geography.communes.iter().any(duplicate) && !communes.iter().any(duplicate) {
+            communes.push(commune);
+        }
+    }
+    let added = communes.len();
+    *geography = Geography::load(communes);
+    Ok(added)
+}
+
+pub fn distance_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+fn key(text: &str) -> String {
+    let folded = fold(text);
+    let words: Vec<&str> = folded
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| match w {
+            "st" => "saint",
+            "ste" => "sainte",
+            w => w,
+        })
+        .collect();
+    words.join(" ")
+}
+
+fn department_of_postcode(postcode: &str) -> Option<String> {
+    Some(match postcode.get(..2)? {
+        "97" => postcode.get(..3)?.to_string(),
+        "20" if postcode < "20200" => "2A".to_string(),
+        "20" => "2B".to_string(),
+        prefix => prefix.to_string(),
+    })
+}
+
+fn parse_communes(csv: &str) -> Result<Vec<Commune>, String> {
+    let mut communes = Vec::new();
+    let mut problems = Vec::new();
+    for (line, r) in rows(csv).enumerate() {
+        let commune = match r[..] {
+            [name, postcode, department, lat, lon] => match (lat.parse(), lon.parse()) {
+                (Ok(lat), Ok(lon))
+                    if postcode.len() == 5 && postcode.bytes().all(|b| b.is_ascii_digit()) =>
+                {
+                    Some(Commune {
+                        name: name.trim().to_string(),
+                        postcode: postcode.to_string(),
+                        department: department.trim().to_string(),
+                        lat,
+                        lon,
+                    })
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        match commune {
+            Some(commune) => communes.push(commune),
+            None => problems.push(format!(
+                "row {} is not `name,postcode,departement,lat,lon`",
+                line + 2
+            )),
+        }
+    }
+    match problems.is_empty() {
+        true => Ok(communes),
+        false => Err(problems.join("; ")),
+    }
+}
+
+fn rows(csv: &str) -> impl Iterator<Item = Vec<&str>> {
+    csv.lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split(',').collect())
+}
+
+impl Geography {
+    fn load(extra: Vec<Commune>) -> Self {
+        let regions: Vec<Region> = rows(REGIONS_CSV)
+            .map(|r| Region {
+                code: r[0].to_string(),
+                name: r[1].to_string(),
+            })
+            .collect();
+        let departments: Vec<Department> = rows(DEPARTEMENTS_CSV)
+            .map(|r| Department {
+                code: r[0].to_string(),
+                name: r[1].to_string(),
+                region: r[2].to_string(),
+            })
+            .collect();
+        let mut communes = parse_communes(COMMUNES_CSV).expect("data/geo/communes.csv is valid");
+        communes.extend(extra.iter().cloned());
+
+        let mut foreign = Vec::new();
+        let mut names = Vec::new();
+        for r in rows(FOREIGN_CSV) {
+            let aliases: Vec<&str> = r[0].split('|').collect();
+            for alias in &aliases {
+                names.push((key(alias), Place::Foreign(foreign.len())));
+            }
+            foreign.push(ForeignCity {
+                name: aliases[0].to_string(),
+                country: r[1].to_string(),
+                lat: r[2].parse().unwrap_or_default(),
+                lon: r[3].parse().unwrap_or_default(),
+            });
+        }
+
+        let mut groups: HashMap<String, usize> = HashMap::new();
+        let mut commune_groups: Vec<Vec<usize>> = Vec::new();
+        let mut add_commune = |name_key: String, idx: usize, names: &mut Vec<(String, Place)>| {
+            let group = *groups.entry(name_key.clone()).or_insert_with(|| {
+                commune_groups.push(Vec::new());
+                names.push((name_key, Place::Communes(commune_groups.len() - 1)));
+                commune_groups.len() - 1
+            });
+            commune_groups[group].push(idx);
+        };
+        for (idx, commune) in communes.iter().enumerate() {
+            add_commune(key(&commune.name), idx, &mut names);
+        }
+        for (alias, name, department) in ALIASES {
+            if let Some(idx) = communes
+                .iter()
+                .position(|c| c.name == *name && c.department == *department)
+            {
+                add_commune(key(alias), idx, &mut names);
+            }
+        }
+
+        for (idx, department) in departments.iter().enumerate() {
+            names.push((key(&department.name), Place::Department(idx)));
+        }
+        for (idx, region) in regions.iter().enumerate() {
+            names.push((key(&region.name), Place::Region(idx)));
+        }
+        names.push(("france".to_string(), Place::Country));
+
+        Self {
+            regions,
+            departments,
+            communes,
+            extra,
+            foreign,
+            commune_groups,
+            names,
+        }
+    }
+
+    fn resolve(&self, raw: &str) -> Option<Location> {
+        let mut text = key(raw);
+        if text.is_empty() {
+            return None;
+        }
+
+        let postcode = POSTCODE.captures(&text).map(|c| c[1].to_string());
+        let mut hints: Vec<String> = postcode
+            .iter()
+            .filter_map(|p| department_of_postcode(p))
+            .collect();
+
+        let mut arrondissement = None;
+        if let Some(c) = ARRONDISSEMENT.captures(&text) {
+            let n: u32 = c[2].parse().unwrap_or(0);
+            let (base, max) = match &c[1] {
+                "paris" => (75000, 20),
+                "lyon" => (69000, 9),
+                _ => (13000, 16),
+            };
+            let explicit = c.get(3).is_some()
+                || (n != base / 1000
+                    && SPACED_ARRONDISSEMENT
+                        .captures_iter(&fold(raw))
+                        .any(|s| s[1] == c[1] && s[2].parse() == Ok(n)));
+            if explicit && (1..=max).contains(&n) {
+                arrondissement = Some(format!("{:05}", base + n));
+                let start = c.get(2).map(|m| m.start()).unwrap_or_default();
+                let end = c.get(0).map(|m| m.end()).unwrap_or_default();
+                text.replace_range(start..end, "");
+            }
+        }
+        for c in DEPARTMENT_CODE.captures_iter(&text) {
+            let code = c[1].to_uppercase();
+            if self.departments.iter().any(|d| d.code == code) {
+                hints.push(code);
+            }
+        }
+
+        let places = self.match_places(&text);
+        for place in &places {
+            match *place {
+                Place::Department(idx) => hints.push(self.departments[idx].code.clone()),
+                Place::Region(idx) => hints.extend(
+                    self.departments
+                        .iter()
+                        .filter(|d| d.region == self.regions[idx].code)
+                        .map(|d| d.code.clone()),
+                ),
+                _ => {}
+            }
+        }
+
+        let commune = places.iter().find_map(|place| match *place {
+            Place::Communes(group) => {
+                let candidates = &self.commune_groups[group];
+                if hints.is_empty() {
+                    candidates.first().copied()
+                } else {
+                    candidates
+                        .iter()
+                        .copied()
+                        .find(|&c| hints.contains(&self.communes[c].department))
+                }
+            }
+            _ => None,
+        });
+        if let Some(idx) = commune {
+            let commune = &self.communes[idx];
+            let postcode = postcode
+                .filter(|p| department_of_postcode(p).as_ref() == Some(&commune.department))
+                .or(arrondissement)
+                .unwrap_or_else(|| commune.postcode.clone());
+            let mut location = self.department_location(&commune.department);
+            location.city = Some(commune.name.clone());
+            location.postcode = Some(postcode);
+            location.lat = Some(commune.lat);
+            location.lon = Some(commune.lon);
+            return Some(location);
+        }
+
+        if let Some(city) = places.iter().find_map(|place| match *place {
+            Place::Foreign(idx) => Some(&self.foreign[idx]),
+            _ => None,
+        }) {
+            return Some(Location {
+                city: Some(city.name.clone()),
+                country: Some(city.country.clone()),
+                lat: Some(city.lat),
+                lon: Some(city.lon),
+                ..Default::default()
+            });
+        }
+
+        let department = places
+            .iter()
+            .find_map(|place| match *place {
+                Place::Department(idx) => Some(self.departments[idx].code.clone()),
+                _ => None,
+            })
+            .or_else(|| postcode.as_deref().and_then(department_of_postcode))
+            .or_else(|| {
+                hints
+                    .first()
+                    .filter(|_| !places.iter().any(|p| matches!(p, Place::Region(_))))
+                    .cloned()
+            });
+        if let Some(code) = department {
+            let mut location = self.department_location(&code);
+            location.postcode = postcode;
+            return Some(location);
+        }
+
+        if let Some(region) = places.iter().find_map(|place| match *place {
+            Place::Region(idx) => Some(&self.regions[idx]),
+            _ => None,
+        }) {
+            return Some(Location {
+                region: Some(region.name.clone()),
+                country: Some("France".to_string()),
+                ..Default::default()
+            });
+        }
+
+        if places.contains(&Place::Country) {
+            return Some(Location {
+                country: Some("France".to_string()),
+                ..Default::default()
+            });
+        }
+        None
+    }
+
+    fn match_places(&self, text: &str) -> Vec<Place> {
+        let padded = format!(" {} ", text);
+        let mut matches: Vec<(usize, usize, Place)> = Vec::new();
+        for (name, place) in &self.names {
+            let needle = format!(" {} ", name);
+            for (start, _) in padded.match_indices(&needle) {
+                matches.push((start, start + needle.len() - 1, *place));
+            }
+        }
+        matches.sort_by_key(|(start, end, _)| (std::cmp::Reverse(end - start), *start));
+
+        let mut accepted: Vec<(usize, usize, Place)> = Vec::new();
+        for (start, end, place) in matches {
+            if accepted.iter().all(|(s, e, _)| end <= *s || start >= *e) {
+                accepted.push((start, end, place));
+            }
+        }
+        accepted.into_iter().map(|(_, _, place)| place).collect()
+    }
+
+    fn department_location(&self, code: &str) -> Location {
+        let department = self.departments.iter().find(|d| d.code == code);
+        let region = department.and_then(|d| self.regions.iter().find(|r| r.code == d.region));
+        Location {
+            department: department.map(|d| d.name.clone()),
+            department_code: department.map(|d| d.code.clone()),
+            region: region.map(|r| r.name.clone()),
+            country: Some("France".to_string()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use scraper::{ElementRef, Html};
+
+const SKIPPED: &[&str] = &[
+    "script", "style", "noscript", "iframe", "svg", "button", "form", "head", "template", "img",
+    "input", "select",
+];
+const BLOCKS: &[&str] = &[
+    "p", "div", "section", "article", "header", "footer", "main", "aside", "table", "tbody",
+    "thead", "dl", "dd", "dt", "figure", "nav",
+];
+const ESCAPED: &[char] = &['\\', '`', '*', '_', '[', ']', '#'];
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid",
+    "gclid",
+    "msclkid",
+    "mc_cid",
+    "mc_eid",
+    "trk",
+    "trkinfo",
+    "refid",
+    "trackingid",
+    "xtor",
+];
+
+pub fn html_to_markdown(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut out = String::new();
+    render_children(fragment.root_element(), &mut out, 0);
+    clean(&out)
+}
+
+pub fn element_to_markdown(element: ElementRef<'_>) -> String {
+    let mut out = String::new();
+    render_element(element, &mut out, 0);
+    clean(&out)
+}
+
+fn render_children(element: ElementRef<'_>, out: &mut String, depth: usize) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            push_text(out, text);
+        } else if let Some(child) = ElementRef::wrap(child) {
+            render_element(child, out, depth);
+        }
+    }
+}
+
+fn render_element(element: ElementRef<'_>, out: &mut String, depth: usize) {
+    let name = element.value().name();
+    if SKIPPED.contains(&name) {
+        return;
+    }
+    match name {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let inner = render_inline(element, depth);
+            if !inner.is_empty() {
+                blank_line(out);
+                out.push_str(&"#".repeat(name[1..].parse().unwrap_or(1)));
+                out.push(' ');
+                out.push_str(&inner);
+                blank_line(out);
+            }
+        }
+        "ul" | "ol" => render_list(element, out, depth, name == "ol"),
+        "li" => {
+            new_line(out);
+            out.push_str("- ");
+            render_children(element, out, depth + 1);
+            new_line(out);
+        }
+        "br" => {
+            new_line(out);
+            out.push_str(&"  ".repeat(depth));
+        }
+        "hr" => {
+            blank_line(out);
+            out.push_str("---");
+            blank_line(out);
+        }
+        "strong" | "b" => wrap_inline(element, out, depth, "**"),
+        "em" | "i" => wrap_inline(element, out, depth, "*"),
+        "code" => {
+            let text: String = element.text().collect();
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !text.is_empty() {
+                space_before(out);
+                out.push_str(&format!("`{}`", text));
+            }
+        }
+        "pre" => {
+            let text: String = element.text().collect();
+            blank_line(out);
+            out.push_str("```\n");
+            out.push_str(text.trim_matches('\n'));
+            out.push_str("\n```");
+            blank_line(out);
+        }
+        "blockquote" => {
+            let mut inner = String::new();
+            render_children(element, &mut inner, depth);
+            let inner = clean(&inner);
+            if !inner.is_empty() {
+                blank_line(out);
+                let quoted: Vec<String> = inner.lines().map(|line| format!("> {}", line)).collect();
+                out.push_str(&quoted.join("\n"));
+                blank_line(out);
+            }
+        }
+        "a" => {
+            let text = render_inline(element, depth);
+            if text.is_empty() {
+                return;
+            }
+            let href = element.value().attr("href").map(clean_href);
+            match href.filter(|h| is_link(h)) {
+                Some(href) => {
+                    space_before(out);
+                    out.push_str(&format!("[{}]({})", text, href));
+                }
+                None => push_words(out, &text),
+            }
+        }
+        "tr" => {
+            new_line(out);
+            let cells: Vec<String> = element
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+                .map(|cell| render_inline(cell, depth))
+                .collect();
+            out.push_str(&cells.join(" | "));
+            new_line(out);
+        }
+        _ if BLOCKS.contains(&name) => {
+            blank_line(out);
+            render_children(element, out, depth);
+            blank_line(out);
+        }
+        _ => render_children(element, out, depth),
+    }
+}
+
+fn render_list(element: ElementRef<'_>, out: &mut String, depth: usize, ordered: bool) {
+    if depth == 0 {
+        blank_line(out);
+    }
+    let items = element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|item| item.value().name() == "li");
+    for (n, item) in items.enumerate() {
+        new_line(out);
+        out.push_str(&"  ".repeat(depth));
+        if ordered {
+            out.push_str(&format!("{}. ", n + 1));
+        } else {
+            out.push_str("- ");
+        }
+        render_children(item, out, depth + 1);
+        new_line(out);
+    }
+    if depth == 0 {
+        blank_line(out);
+    }
+}
+
+fn render_inline(element: ElementRef<'_>, depth: usize) -> String {
+    let mut inner = String::new();
+    render_children(element, &mut inner, depth);
+    inner.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn wrap_inline(element: ElementRef<'_>, out: &mut String, depth: usize, marker: &str) {
+    let inner = render_inline(element, depth);
+    if inner.is_empty() {
+        return;
+    }
+    space_before(out);
+    out.push_str(marker);
+    out.push_str(&inner);
+    out.push_str(marker);
+}
+
+fn push_text(out: &mut String, text: &str) {
+    push_words(out, &escape(text));
+}
+
+fn push_words(out: &mut String, text: &str) {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if text.starts_with(char::is_whitespace) || words.is_empty() {
+        space_before(out);
+    }
+    if words.is_empty() {
+        return;
+    }
+    if ends_inline(out) && !out.ends_with(' ') && starts_word(words[0]) {
+        out.push(' ');
+    }
+    out.push_str(&words.join(" "));
+    if text.ends_with(char::is_whitespace) {
+        out.push(' ');
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if ESCAPED.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn space_before(out: &mut String) {
+    if ends_inline(out) && !out.ends_with(' ') {
+        out.push(' ');
+    }
+}
+
+fn ends_inline(out: &str) -> bool {
+    let line = out.rsplit('\n').next().unwrap_or_default();
+    let content = line.trim_start();
+    !(content.is_empty() || content == "- " || content.strip_suffix(". ").is_some_and(is_number))
+}
+
+fn starts_word(word: &str) -> bool {
+    !word.starts_with([',', '.', ':', ';', ')', '!', '?'])
+}
+
+fn is_number(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_link(href: &str) -> bool {
+    !(href.is_empty() || href.starts_with('#') || href.starts_with("javascript:"))
+}
+
+fn new_line(out: &mut String) {
+    out.truncate(out.trim_end_matches(' ').len());
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn blank_line(out: &mut String) {
+    new_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+fn clean(markdown: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in markdown.lines().map(str::trim_end) {
+        if !line.trim().is_empty() {
+            lines.push(line);
+        } else if lines.last().is_some_and(|last| !last.is_empty()) {
+            lines.push("");
+        }
+    }
+    lines.join("\n").trim().to_string()
+}
+
+fn clean_href(href: &str) -> String {
+    let href = href.trim();
+    let Ok(mut url) = url::Url::parse(href) else {
+        return href.to_string();
+    };
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| {
+            let key = key.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    url.to_string()
+}
@@ -1,15 +1,105 @@
-use chrono::NaiveDate;
+use crate::dates::PostedDate;
+use crate::error::{Result, ScraperError};
+use crate::experience::{ExperienceLevel, YearsRange};
+use crate::location::Location;
+use crate::scoring::{Profile, Score};
+use crate::skills::Taxonomy;
+use crate::text::{contains_words, same_company};
+use crate::transforms::Transform;
+use crate::xpath::XPath;
+use chrono::{DateTime, Utc};
+use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::PathBuf;
+use url::Url;
 
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Job {
     pub id: String,
     pub title: String,
     pub company: String,
+    pub company_url: Option<String>,
+    pub company_logo: Option<String>,
+    pub company_size: Option<String>,
+    pub company_sector: Option<String>,
     pub location: String,
+    pub normalized_location: Option<Location>,
+    pub distance_km: Option<f64>,
     pub description: String,
-    pub date_posted: NaiveDate,
+    pub sections: BTreeMap<String, String>,
+    pub date_posted: PostedDate,
     pub url: String,
+    pub apply_url: Option<String>,
     pub source: String,
+    pub tags: Vec<String>,
+    pub skills: Vec<String>,
+    pub score: Option<Score>,
+    pub contract: Option<String>,
+    pub salary: Option<Salary>,
+    pub valid_through: Option<DateTime<Utc>>,
+    pub experience_level: Option<ExperienceLevel>,
+    pub experience_years: Option<YearsRange>,
+    pub facts: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Salary {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub currency: Option<String>,
+    pub period: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchReport {
+    pub jobs: Vec<Job>,
+    pub errors: Vec<ScraperError>,
+    pub card_errors: Vec<ScraperError>,
+    pub warnings: Vec<ScraperError>,
+    pub selector_matches: Vec<SelectorMatch>,
+    pub artifacts: Vec<PathBuf>,
+}
+
+impl SearchReport {
+    pub fn into_result(self, all_boards: bool) -> Result<Vec<Job>> {
+        if all_boards || !self.jobs.is_empty() {
+            return Ok(self.jobs);
+        }
+        match self.errors.into_iter().chain(self.card_errors).next() {
+            Some(e) => Err(e),
+            None => Ok(self.jobs),
+        }
+    }
+
+    pub fn record_match(&mut self, board: &str, field: &str, alternative: usize, selector: &str) {
+        match self
+            .selector_matches
+            .iter_mut()
+            .find(|m| m.board == board && m.field == field && m.alternative == alternative)
+        {
+            Some(existing) => existing.count += 1,
+            None => self.selector_matches.push(SelectorMatch {
+                board: board.to_string(),
+                field: field.to_string(),
+                alternative,
+                selector: selector.to_string(),
+                count: 1,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectorMatch {
+    pub board: String,
+    pub field: String,
+    pub alternative: usize,
+    pub selector: String,
+    pub count: usize,
 }
 
 #[derive(Clone, Debug, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -36,6 +126,7 @@ impl Board {
             .unwrap()
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         BOARD_MAP
             .iter()
@@ -52,12 +143,69 @@ impl Board {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct Radius {
+    pub center: String,
+    pub coordinates: Option<(f64, f64)>,
+    pub km: f64,
+    pub keep_unlocated: bool,
+}
+
+impl Radius {
+    pub fn new<S: Into<String>>(center: S, km: f64) -> Self {
+        let center = center.into();
+        Self {
+            coordinates: crate::location::normalize(&center).and_then(|l| l.coordinates()),
+            center,
+            km,
+            keep_unlocated: true,
+        }
+    }
+
+    pub fn distance_km(&self, location: Option<&Location>) -> Option<f64> {
+        let to = location?.coordinates()?;
+        Some(crate::location::distance_km(self.coordinates?, to))
+    }
+
+    pub fn check(&self, location: Option<&Location>) -> CardCheck {
+        match self.distance_km(location) {
+            Some(distance) if distance <= self.km => CardCheck::Keep,
+            Some(_) => CardCheck::Reject,
+            None => CardCheck::Unlocated {
+                kept: self.keep_unlocated,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardCheck {
+    Keep,
+    Reject,
+    Unlocated { kept: bool },
+}
+
+impl CardCheck {
+    pub fn is_kept(self) -> bool {
+        matches!(self, CardCheck::Keep | CardCheck::Unlocated { kept: true })
+    }
+}
+
 #[derive(Clone)]
 pub struct JobSearchParams {
     pub query: String,
     pub board: Board,
     pub location: String,
     pub limit: u32,
+    pub max_pages: u32,
+    pub radius: Option<Radius>,
+    pub taxonomy: Taxonomy,
+    pub required_skills: Vec<String>,
+    pub excluded_skills: Vec<String>,
+    pub profile: Option<Profile>,
+    pub sort_by_score: bool,
+    pub keywords: KeywordFilters,
+    pub companies: CompanyFilter,
 }
 
 impl Default for JobSearchParams {
@@ -67,6 +215,167 @@ impl Default for JobSearchParams {
             board: Board::All,
             location: String::new(),
             limit: 50,
+            max_pages: 20,
+            radius: None,
+            taxonomy: Taxonomy::default(),
+            required_skills: Vec::new(),
+            excluded_skills: Vec::new(),
+            profile: None,
+            sort_by_score: false,
+            keywords: KeywordFilters::default(),
+            companies: CompanyFilter::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Pagination {
+    pub max_pages: u32,
+    pages: u32,
+    seen: HashSet<String>,
+}
+
+impl Pagination {
+    pub fn new(max_pages: u32) -> Self {
+        Self {
+            max_pages,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_new(&mut self, card: &str) -> bool {
+        self.seen.insert(card.to_string())
+    }
+
+    pub fn next_page(&mut self, new_cards: usize) -> bool {
+        self.pages += 1;
+        new_cards > 0 && self.pages < self.max_pages
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompanyFilter {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl CompanyFilter {
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    pub fn matches(&self, company: &str) -> bool {
+        (self.allow.is_empty() || self.allow.iter().any(|entry| same_company(company, entry)))
+            && !self.deny.iter().any(|entry| same_company(company, entry))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeywordField {
+    Title,
+    Company,
+    Description,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeywordFilter {
+    pub must_contain: Vec<String>,
+    pub must_not_contain: Vec<String>,
+}
+
+impl KeywordFilter {
+    pub fn is_empty(&self) -> bool {
+        self.must_contain.is_empty() && self.must_not_contain.is_empty()
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        self.must_contain
+            .iter()
+            .all(|keyword| contains_words(text, keyword))
+            && !self
+                .must_not_contain
+                .iter()
+                .any(|keyword| contains_words(text, keyword))
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeywordFilters {
+    pub title: KeywordFilter,
+    pub company: KeywordFilter,
+    pub description: KeywordFilter,
+}
+
+impl KeywordFilters {
+    pub fn field(&self, field: KeywordField) -> &KeywordFilter {
+        match field {
+            KeywordField::Title => &self.title,
+            KeywordField::Company => &self.company,
+            KeywordField::Description => &self.description,
+        }
+    }
+
+    pub fn field_mut(&mut self, field: KeywordField) -> &mut KeywordFilter {
+        match field {
+            KeywordField::Title => &mut self.title,
+            KeywordField::Company => &mut self.company,
+            KeywordField::Description => &mut self.description,
+        }
+    }
+
+    pub fn matches(&self, job: &Job) -> bool {
+        self.title.matches(&job.title)
+            && self.company.matches(&job.company)
+            && self.description.matches(&job.description)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CareerSite {
+    pub name: String,
+    pub listing_url: String,
+    pub link_selector: String,
+}
+
+impl CareerSite {
+    pub fn new<N, U, S>(name: N, listing_url: U, link_selector: S) -> Self
+    where
+        N: Into<String>,
+        U: Into<String>,
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            listing_url: listing_url.into(),
+            link_selector: link_selector.into(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+        if let Err(e) = Url::parse(&self.listing_url) {
+            problems.push(format!(
+                "listing_url `{}` does not parse: {}",
+                self.listing_url, e
+            ));
+        }
+        if let Err(e) = Selector::parse(&self.link_selector) {
+            problems.push(format!(
+                "link selector `{}` does not parse: {:?}",
+                self.link_selector, e
+            ));
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ScraperError::InvalidConfig {
+                board: self.name.clone(),
+                problems,
+            })
         }
     }
 }
@@ -80,21 +389,142 @@ pub struct Selectors {
     pub location: Rule,
     pub description: Rule,
     pub date_posted: Rule,
+    pub sections: &'static [(&'static str, Rule)],
+    pub apply_url: Option<Rule>,
+    pub company_url: Option<Rule>,
+    pub company_logo: Option<Rule>,
+    pub company_size: Option<Rule>,
+    pub company_sector: Option<Rule>,
+    pub tags: Option<Rule>,
+    pub facts: Option<Rule>,
+    pub labels: &'static [(&'static str, JobField)],
 }
 
-#[derive(Clone)]
+impl Selectors {
+    pub fn rules(&self) -> [(&'static str, &Rule); 7] {
+        [
+            ("card", &self.card),
+            ("id", &self.id),
+            ("title", &self.title),
+            ("company", &self.company),
+            ("location", &self.location),
+            ("description", &self.description),
+            ("date_posted", &self.date_posted),
+        ]
+    }
+
+    pub fn optional_rules(&self) -> [(&'static str, Option<&Rule>); 7] {
+        [
+            ("apply_url", self.apply_url.as_ref()),
+            ("company_url", self.company_url.as_ref()),
+            ("company_logo", self.company_logo.as_ref()),
+            ("company_size", self.company_size.as_ref()),
+            ("company_sector", self.company_sector.as_ref()),
+            ("tags", self.tags.as_ref()),
+            ("facts", self.facts.as_ref()),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobField {
+    Contract,
+    Salary,
+    Location,
+    Company,
+    Experience,
+    Education,
+    Remote,
+    StartDate,
+    CompanySize,
+    CompanySector,
+}
+
+impl JobField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobField::Contract => "contract",
+            JobField::Salary => "salary",
+            JobField::Location => "location",
+            JobField::Company => "company",
+            JobField::Experience => "experience",
+            JobField::Education => "education",
+            JobField::Remote => "remote",
+            JobField::StartDate => "start_date",
+            JobField::CompanySize => "company_size",
+            JobField::CompanySector => "company_sector",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Selects {
+    Css(&'static str),
+    XPath(&'static str),
+}
+
+impl Selects {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Selects::Css(selector) | Selects::XPath(selector) => selector,
+        }
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match self {
+            Selects::Css(selector) => Selector::parse(selector)
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e)),
+            Selects::XPath(expression) => XPath::parse(expression).map(|_| ()),
+        }
+    }
+
+    pub fn select<'a>(
+        &self,
+        document: &'a Html,
+    ) -> std::result::Result<Vec<ElementRef<'a>>, String> {
+        match self {
+            Selects::Css(selector) => {
+                let selector = Selector::parse(selector).map_err(|e| format!("{:?}", e))?;
+                Ok(document.select(&selector).collect())
+            }
+            Selects::XPath(expression) => Ok(XPath::parse(expression)?.select(document)),
+        }
+    }
+}
+
+impl fmt::Display for Selects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selects::Css(selector) => f.write_str(selector),
+            Selects::XPath(expression) => write!(f, "xpath:{}", expression),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
 pub enum RuleReturns {
     Text,
     Attribute(&'static str),
     Html,
+    Markdown,
+    KeyValues,
 }
 
 #[derive(Clone)]
 pub struct Rule {
-    pub selects: &'static str,
+    pub selects: Selects,
     pub n: Option<(usize, usize)>,
     pub returns: RuleReturns,
-    pub transforms: Option<fn(&str) -> String>,
+    pub transforms: Cow<'static, [Transform]>,
+    pub alternatives: &'static [Rule],
+}
+
+impl Rule {
+    pub fn candidates(&self) -> impl Iterator<Item = &Rule> {
+        std::iter::once(self).chain(self.alternatives)
+    }
 }
 
 pub enum PageQuery<'a> {
@@ -0,0 +1,240 @@
+use crate::models::{Job, Salary};
+use crate::skills::Taxonomy;
+use crate::text::{contains_words, fold};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+const EXCLUDED_KEYWORD_POINTS: f64 = -10.0;
+const LOCATION_POINTS: f64 = 3.0;
+const SALARY_POINTS: f64 = 2.0;
+const CONTRACT_POINTS: f64 = 2.0;
+const REMOTE_POINTS: f64 = 2.0;
+
+const REMOTE_PHRASES: &[(&str, RemoteMode)] = &[
+    ("full remote", RemoteMode::Full),
+    ("100% remote", RemoteMode::Full),
+    ("100% teletravail", RemoteMode::Full),
+    ("teletravail total", RemoteMode::Full),
+    ("teletravail complet", RemoteMode::Full),
+    ("fully remote", RemoteMode::Full),
+    ("hybride", RemoteMode::Hybrid),
+    ("hybrid", RemoteMode::Hybrid),
+    ("teletravail partiel", RemoteMode::Hybrid),
+    ("teletravail frequent", RemoteMode::Hybrid),
+    ("teletravail occasionnel", RemoteMode::Hybrid),
+    ("jours de teletravail", RemoteMode::Hybrid),
+    ("pas de teletravail", RemoteMode::OnSite),
+    ("teletravail non autorise", RemoteMode::OnSite),
+    ("sur site", RemoteMode::OnSite),
+    ("on-site", RemoteMode::OnSite),
+];
+const NEGATIONS_BEFORE: &[&str] = &["pas", "sans", "aucun", "no", "not", "non"];
+const NEGATIONS_AFTER: &[&str] = &[
+    "n'est pas",
+    "pas possible",
+    "impossible",
+    "exclu",
+    "not possible",
+    "not available",
+];
+
+const WORKING_DAYS_PER_YEAR: f64 = 218.0;
+const WORKING_HOURS_PER_YEAR: f64 = 1607.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteMode {
+    OnSite,
+    Hybrid,
+    Full,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub skills: Vec<(String, f64)>,
+    pub excluded_keywords: Vec<String>,
+    pub preferred_locations: Vec<String>,
+    pub min_salary: Option<f64>,
+    pub contracts: Vec<String>,
+    pub remote: Option<RemoteMode>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Score {
+    pub total: f64,
+    pub factors: Vec<Factor>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Factor {
+    pub name: String,
+    pub points: f64,
+    pub reason: String,
+}
+
+impl Score {
+    fn add(&mut self, name: &str, points: f64, reason: String) {
+        self.total += points;
+        self.factors.push(Factor {
+            name: name.to_string(),
+            points,
+            reason,
+        });
+    }
+}
+
+pub fn score(job: &Job, profile: &Profile, taxonomy: &Taxonomy) -> Score {
+    let mut score = Score::default();
+
+    for (wanted, weight) in &profile.skills {
+        let name = taxonomy
+            .canonical(wanted)
+            .map(|skill| skill.name.as_str())
+            .unwrap_or(wanted);
+        if job.skills.iter().any(|skill| fold(skill) == fold(name)) {
+            score.add("skill", *weight, format!("mentions {}", name));
+        }
+    }
+
+    for keyword in &profile.excluded_keywords {
+        if contains_words(&job.title, keyword) || contains_words(&job.description, keyword) {
+            score.add(
+                "excluded_keyword",
+                EXCLUDED_KEYWORD_POINTS,
+                format!("mentions excluded keyword {:?}", keyword),
+            );
+        }
+    }
+
+    if let Some(preferred) = profile
+        .preferred_locations
+        .iter()
+        .find(|preferred| in_location(job, preferred))
+    {
+        score.add(
+            "location",
+            LOCATION_POINTS,
+            format!("located in {}", preferred),
+        );
+    }
+
+    if let (Some(minimum), Some(salary)) = (profile.min_salary, &job.salary) {
+        if let Some(yearly) = yearly_salary(salary) {
+            if yearly >= minimum {
+                score.add(
+                    "salary",
+                    SALARY_POINTS,
+                    format!("pays up to {:.0} a year, at least {:.0}", yearly, minimum),
+                );
+            } else {
+                score.add(
+                    "salary",
+                    -SALARY_POINTS,
+                    format!("pays at most {:.0} a year, below {:.0}", yearly, minimum),
+                );
+            }
+        }
+    }
+
+    if let (false, Some(contract)) = (profile.contracts.is_empty(), &job.contract) {
+        match profile
+            .contracts
+            .iter()
+            .find(|wanted| contains_words(contract, wanted))
+        {
+            Some(wanted) => score.add("contract", CONTRACT_POINTS, format!("{} contract", wanted)),
+            None => score.add(
+                "contract",
+                -CONTRACT_POINTS,
+                format!("{} is not a wanted contract", contract),
+            ),
+        }
+    }
+
+    if let (Some(wanted), Some(mode)) = (profile.remote, remote_mode(job)) {
+        if wanted == mode {
+            score.add("remote", REMOTE_POINTS, format!("{:?} remote policy", mode));
+        } else {
+            score.add(
+                "remote",
+                -REMOTE_POINTS,
+                format!("{:?} remote policy, {:?} wanted", mode, wanted),
+            );
+        }
+    }
+
+    score
+}
+
+pub fn sort_by_score(jobs: &mut [Job]) {
+    let total = |job: &Job| job.score.as_ref().map_or(f64::NEG_INFINITY, |s| s.total);
+    jobs.sort_by(|a, b| total(b).partial_cmp(&total(a)).unwrap_or(Ordering::Equal));
+}
+
+pub fn remote_mode(job: &Job) -> Option<RemoteMode> {
+    let sources = job
+        .facts
+        .get("remote")
+        .into_iter()
+        .chain(&job.tags)
+        .chain([&job.title, &job.description]);
+    for text in sources {
+        let text = fold(text);
+        let mode = REMOTE_PHRASES
+            .iter()
+            .find(|(phrase, _)| {
+                text.match_indices(phrase)
+                    .any(|(start, found)| !negated(&text, start, start + found.len()))
+            })
+            .map(|(_, mode)| *mode);
+        if mode.is_some() {
+            return mode;
+        }
+    }
+    None
+}
+
+fn negated(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].split_whitespace().rev().take(2);
+    let after = text[end..]
+        .split(['.', ';', ',', '!', '?', '\n'])
+        .next()
+        .unwrap_or_default();
+    before
+        .into_iter()
+        .any(|word| NEGATIONS_BEFORE.contains(&word))
+        || NEGATIONS_AFTER
+            .iter()
+            .any(|negation| after.contains(negation))
+}
+
+fn in_location(job: &Job, preferred: &str) -> bool {
+    if contains_words(&job.location, preferred) {
+        return true;
+    }
+    let Some(location) = &job.normalized_location else {
+        return false;
+    };
+    [
+        &location.city,
+        &location.department,
+        &location.department_code,
+        &location.region,
+    ]
+    .into_iter()
+    .flatten()
+    .any(|part| fold(part) == fold(preferred.trim()))
+}
+
+fn yearly_salary(salary: &Salary) -> Option<f64> {
+    let amount = salary.max.or(salary.min)?;
+    let factor = match salary.period.as_deref().map(str::to_uppercase).as_deref() {
+        Some("HOUR") => WORKING_HOURS_PER_YEAR,
+        Some("DAY") => WORKING_DAYS_PER_YEAR,
+        Some("WEEK") => 52.0,
+        Some("MONTH") => 12.0,
+        _ => 1.0,
+    };
+    Some(amount * factor)
+}
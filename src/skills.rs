@@ -0,0 +1,205 @@
+use crate::models::Job;
+use crate::text::fold;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+const BUILTIN_JSON: &str = include_str!("../data/skills.json");
+
+static BUILTIN: Lazy<Taxonomy> =
+    Lazy::new(|| serde_json::from_str(BUILTIN_JSON).expect("data/skills.json is valid"));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillCategory {
+    Language,
+    Framework,
+    Cloud,
+    Database,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Skill {
+    pub name: String,
+    pub category: SkillCategory,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default = "match_name")]
+    pub match_name: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "Skills", into = "Skills")]
+pub struct Taxonomy {
+    skills: Vec<Skill>,
+    patterns: Vec<Vec<Regex>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Skills {
+    skills: Vec<Skill>,
+}
+
+impl From<Skills> for Taxonomy {
+    fn from(skills: Skills) -> Self {
+        Taxonomy::new(skills.skills)
+    }
+}
+
+impl From<Taxonomy> for Skills {
+    fn from(taxonomy: Taxonomy) -> Self {
+        Skills {
+            skills: taxonomy.skills,
+        }
+    }
+}
+
+impl PartialEq for Taxonomy {
+    fn eq(&self, other: &Self) -> bool {
+        self.skills == other.skills
+    }
+}
+
+fn match_name() -> bool {
+    true
+}
+
+impl Skill {
+    pub fn new<S: Into<String>>(name: S, category: SkillCategory) -> Self {
+        Self {
+            name: name.into(),
+            category,
+            aliases: Vec::new(),
+            match_name: true,
+            case_sensitive: false,
+        }
+    }
+
+    pub fn case_sensitive(mut self) -> Self {
+        self.case_sensitive = true;
+        self
+    }
+
+    pub fn alias<S: Into<String>>(mut self, alias: S) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    fn spellings(&self) -> impl Iterator<Item = &str> {
+        self.match_name
+            .then_some(self.name.as_str())
+            .into_iter()
+            .chain(self.aliases.iter().map(String::as_str))
+    }
+
+    fn patterns(&self) -> Vec<Regex> {
+        self.spellings()
+            .filter_map(|spelling| pattern(spelling, self.case_sensitive))
+            .collect()
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        let name = fold(name.trim());
+        fold(&self.name) == name || self.aliases.iter().any(|alias| fold(alias) == name)
+    }
+}
+
+impl Default for Taxonomy {
+    fn default() -> Self {
+        BUILTIN.clone()
+    }
+}
+
+impl Taxonomy {
+    pub fn new(skills: Vec<Skill>) -> Self {
+        let patterns = skills.iter().map(Skill::patterns).collect();
+        Self { skills, patterns }
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    pub fn with_skill(mut self, skill: Skill) -> Self {
+        let patterns = skill.patterns();
+        match self.skills.iter().position(|s| s.is_named(&skill.name)) {
+            Some(index) => {
+                self.skills[index] = skill;
+                self.patterns[index] = patterns;
+            }
+            None => {
+                self.skills.push(skill);
+                self.patterns.push(patterns);
+            }
+        }
+        self
+    }
+
+    pub fn canonical(&self, name: &str) -> Option<&Skill> {
+        self.skills.iter().find(|skill| skill.is_named(name))
+    }
+
+    pub fn extract(&self, texts: &[&str]) -> Vec<String> {
+        let folded: Vec<String> = texts.iter().map(|text| fold(text)).collect();
+        self.skills
+            .iter()
+            .zip(&self.patterns)
+            .filter(|(skill, patterns)| {
+                let texts: Vec<&str> = if skill.case_sensitive {
+                    texts.to_vec()
+                } else {
+                    folded.iter().map(String::as_str).collect()
+                };
+                patterns
+                    .iter()
+                    .any(|pattern| texts.iter().any(|text| pattern.is_match(text)))
+            })
+            .map(|(skill, _)| skill.name.clone())
+            .collect()
+    }
+
+    pub fn satisfies(&self, skills: &[String], required: &[String], excluded: &[String]) -> bool {
+        let has = |wanted: &String| {
+            let name = self
+                .canonical(wanted)
+                .map(|skill| skill.name.as_str())
+                .unwrap_or(wanted);
+            skills.iter().any(|skill| fold(skill) == fold(name))
+        };
+        required.iter().all(has) && !excluded.iter().any(has)
+    }
+}
+
+pub fn count(jobs: &[Job]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for skill in jobs.iter().flat_map(|job| &job.skills) {
+        match counts.iter_mut().find(|(name, _)| name == skill) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((skill.clone(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+fn pattern(spelling: &str, case_sensitive: bool) -> Option<Regex> {
+    let spelling = match case_sensitive {
+        true => spelling.trim().to_string(),
+        false => fold(spelling.trim()),
+    };
+    if spelling.is_empty() {
+        return None;
+    }
+    let words: Vec<String> = spelling.split_whitespace().map(regex::escape).collect();
+    Regex::new(&format!(
+        r"(?:^|[^\w+#.])(?:{})(?:$|[^\w+#.]|\.(?:$|\W))",
+        words.join(r"\s+")
+    ))
+    .ok()
+}
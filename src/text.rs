@@ -0,0 +1,80 @@
+pub fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => folded.push('a'),
+            'æ' => folded.push_str("ae"),
+            'ç' => folded.push('c'),
+            'è' | 'é' | 'ê' | 'ë' => folded.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => folded.push('i'),
+            'ñ' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => folded.push('o'),
+            'œ' => folded.push_str("oe"),
+            'ù' | 'ú' | 'û' | 'ü' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'ß' => folded.push_str("ss"),
+            '’' | '‘' | '`' => folded.push('\''),
+            '\u{a0}' | '\u{202f}' => folded.push(' '),
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+pub fn contains_words(text: &str, words: &str) -> bool {
+    let text = fold(text);
+    let words = fold(words.trim());
+    if words.is_empty() {
+        return false;
+    }
+    text.match_indices(&words).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + words.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+const LEGAL_FORMS: &[&str] = &[
+    "sa",
+    "sas",
+    "sasu",
+    "sarl",
+    "eurl",
+    "sca",
+    "snc",
+    "se",
+    "inc",
+    "ltd",
+    "llc",
+    "plc",
+    "gmbh",
+    "ag",
+    "bv",
+    "nv",
+    "corp",
+    "corporation",
+];
+
+pub fn normalize_company(name: &str) -> String {
+    let folded: String = fold(name)
+        .chars()
+        .filter(|c| *c != '.')
+        .map(|c| {
+            if c.is_alphanumeric() || c == '&' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+    while words.len() > 1 && words.last().is_some_and(|word| LEGAL_FORMS.contains(word)) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+pub fn same_company(company: &str, entry: &str) -> bool {
+    let entry = normalize_company(entry);
+    !entry.is_empty() && contains_words(&normalize_company(company), &entry)
+}
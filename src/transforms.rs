@@ -1,42 +1,207 @@
-use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use crate::dates::{parse_date_label, Clock, DatePrecision, SystemClock};
+use chrono::{NaiveDate, NaiveDateTime};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use url::Url;
 
-pub fn hellowork_date(text: &str) -> String {
-    let text = text.trim().to_lowercase();
-    let today = Utc::now().date_naive();
-
-    let date = if text.contains("semaine") || text.contains("week") {
-        let n = text
-            .split_whitespace()
-            .next()
-            .unwrap_or("1")
-            .parse::<i64>()
-            .unwrap_or(1);
-        today - Duration::weeks(n)
-    } else if text.contains("jour") || text.contains("day") {
-        let n = text
-            .split_whitespace()
-            .next()
-            .unwrap_or("1")
-            .parse::<i64>()
-            .unwrap_or(1);
-        today - Duration::days(n)
-    } else {
-        today
-    };
-
-    date.to_string()
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Transform {
+    RegexCapture {
+        pattern: Cow<'static, str>,
+        #[serde(default = "first_group")]
+        group: usize,
+    },
+    Replace {
+        pattern: Cow<'static, str>,
+        with: Cow<'static, str>,
+    },
+    Trim,
+    CollapseWhitespace,
+    Lowercase,
+    SplitNth {
+        separator: Cow<'static, str>,
+        index: isize,
+    },
+    StripPrefix {
+        prefix: Cow<'static, str>,
+    },
+    StripSuffix {
+        suffix: Cow<'static, str>,
+    },
+    UrlJoin {
+        base: Cow<'static, str>,
+    },
+    ParseDate {
+        #[serde(default)]
+        format: Option<Cow<'static, str>>,
+    },
+    #[serde(skip)]
+    Custom(CustomTransform),
+}
+
+#[derive(Clone)]
+pub struct CustomTransform(pub Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl fmt::Debug for CustomTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CustomTransform(..)")
+    }
+}
+
+impl PartialEq for CustomTransform {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+static REGEX_CACHE: Lazy<Mutex<HashMap<String, Regex>>> = Lazy::new(Default::default);
+
+fn first_group() -> usize {
+    1
+}
+
+pub fn apply(transforms: &[Transform], value: &str) -> Option<String> {
+    apply_with_clock(transforms, value, &SystemClock)
+}
+
+pub fn apply_with_clock(
+    transforms: &[Transform],
+    value: &str,
+    clock: &dyn Clock,
+) -> Option<String> {
+    transforms
+        .iter()
+        .try_fold(value.to_string(), |value, transform| {
+            transform.apply_with_clock(&value, clock)
+        })
 }
 
 pub fn linkedin_id(text: &str) -> String {
-    text.split(':').last().unwrap_or("").to_string()
+    Transform::SplitNth {
+        separator: Cow::Borrowed(":"),
+        index: -1,
+    }
+    .apply(text)
+    .unwrap_or_default()
 }
 
+#[deprecated(note = "use `dates::parse_date_label`")]
+pub fn hellowork_date(text: &str) -> String {
+    label_date(text).to_string()
+}
+
+#[deprecated(note = "use `dates::parse_date_label`")]
 pub fn parse_date(text: &str) -> NaiveDate {
-    if text.contains('T') {
-        NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%SZ")
-            .map(|dt| dt.date())
-            .unwrap_or_else(|_| Utc::now().date_naive())
-    } else {
-        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap_or_else(|_| Utc::now().date_naive())
+    label_date(text)
+}
+
+fn label_date(text: &str) -> NaiveDate {
+    parse_date_label(text, &SystemClock)
+        .map(|parsed| parsed.datetime.date_naive())
+        .unwrap_or_else(|| SystemClock.now().date_naive())
+}
+
+impl Transform {
+    pub fn custom<F>(transform: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        Transform::Custom(CustomTransform(Arc::new(transform)))
+    }
+
+    pub fn apply(&self, value: &str) -> Option<String> {
+        self.apply_with_clock(value, &SystemClock)
+    }
+
+    pub fn apply_with_clock(&self, value: &str, clock: &dyn Clock) -> Option<String> {
+        match self {
+            Transform::RegexCapture { pattern, group } => regex(pattern)?
+                .captures(value)?
+                .get(*group)
+                .map(|m| m.as_str().to_string()),
+            Transform::Replace { pattern, with } => Some(
+                regex(pattern)?
+                    .replace_all(value, with.as_ref())
+                    .into_owned(),
+            ),
+            Transform::Trim => Some(value.trim().to_string()),
+            Transform::CollapseWhitespace => {
+                Some(value.split_whitespace().collect::<Vec<_>>().join(" "))
+            }
+            Transform::Lowercase => Some(value.to_lowercase()),
+            Transform::SplitNth { separator, index } => {
+                let parts: Vec<&str> = value.split(separator.as_ref()).collect();
+                let index = if *index < 0 {
+                    parts.len().checked_sub(index.unsigned_abs())?
+                } else {
+                    *index as usize
+                };
+                parts.get(index).map(|part| part.to_string())
+            }
+            Transform::StripPrefix { prefix } => Some(
+                value
+                    .strip_prefix(prefix.as_ref())
+                    .unwrap_or(value)
+                    .to_string(),
+            ),
+            Transform::StripSuffix { suffix } => Some(
+                value
+                    .strip_suffix(suffix.as_ref())
+                    .unwrap_or(value)
+                    .to_string(),
+            ),
+            Transform::UrlJoin { base } => Url::parse(base)
+                .ok()?
+                .join(value.trim())
+                .ok()
+                .map(|url| url.to_string()),
+            Transform::ParseDate {
+                format: Some(format),
+            } => {
+                let value = value.trim();
+                NaiveDateTime::parse_from_str(value, format)
+                    .map(|d| d.and_utc().to_rfc3339())
+                    .or_else(|_| NaiveDate::parse_from_str(value, format).map(|d| d.to_string()))
+                    .ok()
+            }
+            Transform::ParseDate { format: None } => {
+                let parsed = parse_date_label(value, clock)?;
+                Some(match parsed.precision {
+                    DatePrecision::Day => parsed.datetime.date_naive().to_string(),
+                    _ => parsed.datetime.to_rfc3339(),
+                })
+            }
+            Transform::Custom(transform) => Some((transform.0)(value)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Transform::RegexCapture { pattern, .. } | Transform::Replace { pattern, .. } => {
+                Regex::new(pattern)
+                    .map(|_| ())
+                    .map_err(|e| format!("regex `{}` does not compile: {}", pattern, e))
+            }
+            Transform::UrlJoin { base } => Url::parse(base)
+                .map(|_| ())
+                .map_err(|e| format!("url_join base `{}` does not parse: {}", base, e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn regex(pattern: &str) -> Option<Regex> {
+    let mut cache = REGEX_CACHE.lock().ok()?;
+    if let Some(regex) = cache.get(pattern) {
+        return Some(regex.clone());
     }
+    let regex = Regex::new(pattern).ok()?;
+    cache.insert(pattern.to_string(), regex.clone());
+    Some(regex)
 }
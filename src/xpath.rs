@@ -0,0 +1,589 @@
+use ego_tree::{NodeId, NodeRef};
+use scraper::{ElementRef, Html, Node};
+use std::collections::HashSet;
+
+type NodeSet<'a> = Vec<NodeRef<'a, Node>>;
+
+const FUNCTIONS: &[&str] = &[
+    "position",
+    "last",
+    "not",
+    "contains",
+    "starts-with",
+    "ends-with",
+    "normalize-space",
+    "string",
+    "lower-case",
+    "string-length",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XPath {
+    absolute: bool,
+    steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Expr>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Axis {
+    Child,
+    Descendant,
+    DescendantOrSelf,
+    Itself,
+    Parent,
+    Ancestor,
+    AncestorOrSelf,
+    FollowingSibling,
+    PrecedingSibling,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum NodeTest {
+    Name(String),
+    Element,
+    Text,
+    Node,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, Op, Box<Expr>),
+    Literal(String),
+    Number(f64),
+    Attribute(String),
+    Path(XPath),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Slash,
+    DoubleSlash,
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    At,
+    Comma,
+    Dot,
+    DotDot,
+    Axis(String),
+    Star,
+    Op(Op),
+    Name(String),
+    Literal(String),
+    Number(f64),
+}
+
+enum Value<'a> {
+    Nodes(NodeSet<'a>),
+    Strings(Vec<String>),
+    Number(f64),
+    Bool(bool),
+}
+
+struct Context<'a> {
+    node: NodeRef<'a, Node>,
+    position: usize,
+    size: usize,
+}
+
+impl XPath {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let path = parser.path()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("unexpected {:?} in `{}`", token, expression));
+        }
+        if path
+            .steps
+            .last()
+            .is_some_and(|step| step.test == NodeTest::Text)
+        {
+            return Err(format!(
+                "`{}` selects text nodes; select the element and return its text",
+                expression
+            ));
+        }
+        Ok(path)
+    }
+
+    pub fn select<'a>(&self, document: &'a Html) -> Vec<ElementRef<'a>> {
+        let root = document.tree.root();
+        let selected: HashSet<NodeId> = self.evaluate(root).iter().map(|n| n.id()).collect();
+        root.descendants()
+            .filter(|node| selected.contains(&node.id()))
+            .filter_map(ElementRef::wrap)
+            .collect()
+    }
+
+    fn evaluate<'a>(&self, context: NodeRef<'a, Node>) -> NodeSet<'a> {
+        let start = if self.absolute {
+            context.ancestors().last().unwrap_or(context)
+        } else {
+            context
+        };
+        let mut current = vec![start];
+        for step in &self.steps {
+            let mut next: NodeSet<'a> = Vec::new();
+            let mut seen = HashSet::new();
+            for node in &current {
+                for found in step.apply(*node) {
+                    if seen.insert(found.id()) {
+                        next.push(found);
+                    }
+                }
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+impl Step {
+    fn apply<'a>(&self, node: NodeRef<'a, Node>) -> NodeSet<'a> {
+        let mut nodes: NodeSet<'a> = match self.axis {
+            Axis::Child => node.children().collect(),
+            Axis::Descendant => node.descendants().skip(1).collect(),
+            Axis::DescendantOrSelf => node.descendants().collect(),
+            Axis::Itself => vec![node],
+            Axis::Parent => node.parent().into_iter().collect(),
+            Axis::Ancestor => node.ancestors().collect(),
+            Axis::AncestorOrSelf => std::iter::once(node).chain(node.ancestors()).collect(),
+            Axis::FollowingSibling => node.next_siblings().collect(),
+            Axis::PrecedingSibling => node.prev_siblings().collect(),
+        };
+        nodes.retain(|n| self.test.matches(*n));
+        for predicate in &self.predicates {
+            let size = nodes.len();
+            nodes = nodes
+                .into_iter()
+                .enumerate()
+                .filter(|(i, n)| {
+                    let context = Context {
+                        node: *n,
+                        position: i + 1,
+                        size,
+                    };
+                    match predicate.evaluate(&context) {
+                        Value::Number(position) => position == (i + 1) as f64,
+                        value => value.truthy(),
+                    }
+                })
+                .map(|(_, n)| n)
+                .collect();
+        }
+        nodes
+    }
+}
+
+impl NodeTest {
+    fn matches(&self, node: NodeRef<'_, Node>) -> bool {
+        match (self, node.value()) {
+            (NodeTest::Node, _) => true,
+            (NodeTest::Text, Node::Text(_)) => true,
+            (NodeTest::Element, Node::Element(_)) => true,
+            (NodeTest::Name(name), Node::Element(element)) => {
+                element.name().eq_ignore_ascii_case(name)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Expr {
+    fn evaluate<'a>(&self, context: &Context<'a>) -> Value<'a> {
+        match self {
+            Expr::Or(left, right) => {
+                Value::Bool(left.evaluate(context).truthy() || right.evaluate(context).truthy())
+            }
+            Expr::And(left, right) => {
+                Value::Bool(left.evaluate(context).truthy() && right.evaluate(context).truthy())
+            }
+            Expr::Compare(left, op, right) => Value::Bool(compare(
+                &left.evaluate(context),
+                *op,
+                &right.evaluate(context),
+            )),
+            Expr::Literal(text) => Value::Strings(vec![text.clone()]),
+            Expr::Number(n) => Value::Number(*n),
+            Expr::Attribute(name) => Value::Strings(
+                context
+                    .node
+                    .value()
+                    .as_element()
+                    .and_then(|e| e.attr(name))
+                    .map(|v| vec![v.to_string()])
+                    .unwrap_or_default(),
+            ),
+            Expr::Path(path) => Value::Nodes(path.evaluate(context.node)),
+            Expr::Call(name, args) => call(name, args, context),
+        }
+    }
+}
+
+fn call<'a>(name: &str, args: &[Expr], context: &Context<'a>) -> Value<'a> {
+    let arg = |i: usize| -> String {
+        match args.get(i) {
+            Some(expr) => expr.evaluate(context).string(),
+            None => string_value(context.node),
+        }
+    };
+    match name {
+        "position" => Value::Number(context.position as f64),
+        "last" => Value::Number(context.size as f64),
+        "not" => Value::Bool(!args.first().is_some_and(|a| a.evaluate(context).truthy())),
+        "contains" => Value::Bool(arg(0).contains(&arg(1))),
+        "starts-with" => Value::Bool(arg(0).starts_with(&arg(1))),
+        "ends-with" => Value::Bool(arg(0).ends_with(&arg(1))),
+        "normalize-space" => Value::Strings(vec![normalize(&arg(0))]),
+        "string" => Value::Strings(vec![arg(0)]),
+        "lower-case" => Value::Strings(vec![arg(0).to_lowercase()]),
+        "string-length" => Value::Number(arg(0).chars().count() as f64),
+        _ => unreachable!("functions are checked while parsing"),
+    }
+}
+
+impl Value<'_> {
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Nodes(nodes) => !nodes.is_empty(),
+            Value::Strings(strings) => strings.iter().any(|s| !s.is_empty()),
+            Value::Number(n) => *n != 0.0 && !n.is_nan(),
+            Value::Bool(b) => *b,
+        }
+    }
+
+    fn strings(&self) -> Vec<String> {
+        match self {
+            Value::Nodes(nodes) => nodes.iter().map(|n| string_value(*n)).collect(),
+            Value::Strings(strings) => strings.clone(),
+            Value::Number(n) => vec![n.to_string()],
+            Value::Bool(b) => vec![b.to_string()],
+        }
+    }
+
+    fn string(&self) -> String {
+        self.strings().into_iter().next().unwrap_or_default()
+    }
+}
+
+fn compare(left: &Value<'_>, op: Op, right: &Value<'_>) -> bool {
+    let numeric = matches!(left, Value::Number(_)) || matches!(right, Value::Number(_));
+    let (lefts, rights) = (left.strings(), right.strings());
+    lefts.iter().any(|l| {
+        rights.iter().any(|r| {
+            if numeric || !matches!(op, Op::Eq | Op::Ne) {
+                let (Ok(l), Ok(r)) = (l.trim().parse::<f64>(), r.trim().parse::<f64>()) else {
+                    return false;
+                };
+                match op {
+                    Op::Eq => l == r,
+                    Op::Ne => l != r,
+                    Op::Lt => l < r,
+                    Op::Le => l <= r,
+                    Op::Gt => l > r,
+                    Op::Ge => l >= r,
+                }
+            } else {
+                (l == r) == (op == Op::Eq)
+            }
+        })
+    })
+}
+
+fn string_value(node: NodeRef<'_, Node>) -> String {
+    node.descendants()
+        .filter_map(|n| n.value().as_text().map(|t| t.to_string()))
+        .collect()
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, width) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '/' if next == Some('/') => (Token::DoubleSlash, 2),
+            '/' => (Token::Slash, 1),
+            '[' => (Token::LBracket, 1),
+            ']' => (Token::RBracket, 1),
+            '(' => (Token::LParen, 1),
+            ')' => (Token::RParen, 1),
+            '@' => (Token::At, 1),
+            ',' => (Token::Comma, 1),
+            '*' => (Token::Star, 1),
+            '.' if next == Some('.') => (Token::DotDot, 2),
+            '.' if !next.is_some_and(|n| n.is_ascii_digit()) => (Token::Dot, 1),
+            '=' => (Token::Op(Op::Eq), 1),
+            '!' if next == Some('=') => (Token::Op(Op::Ne), 2),
+            '<' if next == Some('=') => (Token::Op(Op::Le), 2),
+            '<' => (Token::Op(Op::Lt), 1),
+            '>' if next == Some('=') => (Token::Op(Op::Ge), 2),
+            '>' => (Token::Op(Op::Gt), 1),
+            '\'' | '"' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&q| q == c)
+                    .ok_or_else(|| format!("unterminated string in `{}`", expression))?;
+                let literal: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Literal(literal), end + 2)
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|d| d.is_ascii_digit() || **d == '.')
+                    .count();
+                let number: String = chars[i..i + len].iter().collect();
+                let number = number
+                    .parse()
+                    .map_err(|_| format!("invalid number `{}`", number))?;
+                (Token::Number(number), len)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|d| d.is_alphanumeric() || matches!(d, '-' | '_'))
+                    .count();
+                let name: String = chars[i..i + len].iter().collect();
+                if chars.get(i + len) == Some(&':') && chars.get(i + len + 1) == Some(&':') {
+                    (Token::Axis(name), len + 2)
+                } else {
+                    (Token::Name(name), len)
+                }
+            }
+            c => return Err(format!("unexpected `{}` in `{}`", c, expression)),
+        };
+        tokens.push(token);
+        i += width;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn next_if(&mut self, expected: &Token) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            other => Err(format!("expected {:?}, found {:?}", expected, other)),
+        }
+    }
+
+    fn path(&mut self) -> Result<XPath, String> {
+        let mut steps = Vec::new();
+        let absolute = matches!(self.peek(), Some(Token::Slash | Token::DoubleSlash));
+        if self.next_if(&Token::DoubleSlash) {
+            steps.push(descendant_or_self());
+        } else {
+            self.next_if(&Token::Slash);
+        }
+        loop {
+            steps.push(self.step()?);
+            match self.peek() {
+                Some(Token::Slash) => self.pos += 1,
+                Some(Token::DoubleSlash) => {
+                    self.pos += 1;
+                    steps.push(descendant_or_self());
+                }
+                _ => break,
+            }
+        }
+        Ok(XPath { absolute, steps })
+    }
+
+    fn step(&mut self) -> Result<Step, String> {
+        let (axis, test) = match self.next() {
+            Some(Token::Dot) => (Axis::Itself, NodeTest::Node),
+            Some(Token::DotDot) => (Axis::Parent, NodeTest::Node),
+            Some(Token::Axis(name)) => (axis(&name)?, self.node_test()?),
+            Some(Token::At) => return Err(
+                "attribute steps select no element; select the element and return the attribute"
+                    .to_string(),
+            ),
+            Some(_) => {
+                self.pos -= 1;
+                (Axis::Child, self.node_test()?)
+            }
+            None => return Err("expected a step".to_string()),
+        };
+        let mut predicates = Vec::new();
+        while self.peek() == Some(&Token::LBracket) {
+            self.pos += 1;
+            predicates.push(self.or_expr()?);
+            self.expect(Token::RBracket)?;
+        }
+        Ok(Step {
+            axis,
+            test,
+            predicates,
+        })
+    }
+
+    fn node_test(&mut self) -> Result<NodeTest, String> {
+        match self.next() {
+            Some(Token::Star) => Ok(NodeTest::Element),
+            Some(Token::Name(name)) if self.peek() == Some(&Token::LParen) => {
+                self.expect(Token::LParen)?;
+                self.expect(Token::RParen)?;
+                match name.as_str() {
+                    "text" => Ok(NodeTest::Text),
+                    "node" => Ok(NodeTest::Node),
+                    _ => Err(format!("unsupported node test `{}()`", name)),
+                }
+            }
+            Some(Token::Name(name)) => Ok(NodeTest::Name(name)),
+            other => Err(format!("expected a node test, found {:?}", other)),
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and_expr()?;
+        while self.peek() == Some(&Token::Name("or".to_string())) {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, String> {
+        let mut expr = self.comparison()?;
+        while self.peek() == Some(&Token::Name("and".to_string())) {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.comparison()?));
+        }
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.primary()?;
+        if let Some(Token::Op(op)) = self.peek().cloned() {
+            self.pos += 1;
+            return Ok(Expr::Compare(Box::new(left), op, Box::new(self.primary()?)));
+        }
+        Ok(left)
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.peek().cloned() {
+            Some(Token::Literal(text)) => {
+                self.pos += 1;
+                Ok(Expr::Literal(text))
+            }
+            Some(Token::Number(n)) => {
+                self.pos += 1;
+                Ok(Expr::Number(n))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.or_expr()?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::At) => {
+                self.pos += 1;
+                match self.next() {
+                    Some(Token::Name(name)) => Ok(Expr::Attribute(name)),
+                    other => Err(format!("expected an attribute name, found {:?}", other)),
+                }
+            }
+            Some(Token::Name(name))
+                if self.peek_at(1) == Some(&Token::LParen)
+                    && !matches!(name.as_str(), "text" | "node") =>
+            {
+                if !FUNCTIONS.contains(&name.as_str()) {
+                    return Err(format!("unsupported function `{}()`", name));
+                }
+                self.pos += 2;
+                let mut args = Vec::new();
+                if self.peek() != Some(&Token::RParen) {
+                    args.push(self.or_expr()?);
+                    while self.peek() == Some(&Token::Comma) {
+                        self.pos += 1;
+                        args.push(self.or_expr()?);
+                    }
+                }
+                self.expect(Token::RParen)?;
+                Ok(Expr::Call(name, args))
+            }
+            _ => Ok(Expr::Path(self.path()?)),
+        }
+    }
+}
+
+fn descendant_or_self() -> Step {
+    Step {
+        axis: Axis::DescendantOrSelf,
+        test: NodeTest::Node,
+        predicates: Vec::new(),
+    }
+}
+
+fn axis(name: &str) -> Result<Axis, String> {
+    Ok(match name {
+        "child" => Axis::Child,
+        "descendant" => Axis::Descendant,
+        "descendant-or-self" => Axis::DescendantOrSelf,
+        "self" => Axis::Itself,
+        "parent" => Axis::Parent,
+        "ancestor" => Axis::Ancestor,
+        "ancestor-or-self" => Axis::AncestorOrSelf,
+        "following-sibling" => Axis::FollowingSibling,
+        "preceding-sibling" => Axis::PrecedingSibling,
+        _ => return Err(format!("unsupported axis `{}`", name)),
+    })
+}
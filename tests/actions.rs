@@ -0,0 +1,23 @@
+use scraptain::actions::PageAction;
+use std::sync::Mutex;
+
+#[derive(Default)]
+struct MockTab {
+    cookies: Mutex<Vec<String>>,
+}
+
+#[tokio::test]
+async fn test_async_page_actions_can_capture_state() {
+    let cookie = String::from("consent=1");
+    let action = PageAction::<MockTab>::new_async(move |tab| {
+        let cookie = cookie.clone();
+        Box::pin(async move {
+            tab.cookies.lock().unwrap().push(cookie);
+            Ok(())
+        })
+    });
+    let tab = MockTab::default();
+    action.run(&tab).await.unwrap();
+    action.clone().run(&tab).await.unwrap();
+    assert_eq!(*tab.cookies.lock().unwrap(), vec!["consent=1", "consent=1"]);
+}
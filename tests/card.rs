@@ -0,0 +1,196 @@
+use scraper::Html;
+use scraptain::board::{check_card, extract_from_rule, extract_tags, missing_field};
+use scraptain::constants::{LINKEDIN, WTTJ};
+use scraptain::models::Pagination;
+use scraptain::{
+    CardCheck, Job, JobSearchParams, KeywordField, Radius, ScraperError, SearchReport,
+};
+
+#[test]
+fn test_card_tags_are_split_and_deduplicated() {
+    let card = Html::parse_fragment(
+        r#"<li data-testid="search-results-list-item-wrapper">
+            <div data-testid="job-card-tags">
+                <div><span>CDI</span></div>
+                <div><span>Télétravail</span>
+                    <span>fréquent</span></div>
+                <div>CDI</div>
+            </div>
+        </li>"#,
+    );
+    let rule = WTTJ.selectors.tags.as_ref().unwrap();
+    assert_eq!(
+        extract_tags(&card, rule),
+        vec!["CDI", "Télétravail fréquent"]
+    );
+    assert!(extract_tags(&Html::parse_fragment("<li></li>"), rule).is_empty());
+}
+
+#[test]
+fn test_linkedin_offsite_apply_url() {
+    let page = Html::parse_document(
+        r#"<code id="applyUrl" style="display: none"><!--"https://jobs.acme.example/apply/4821?src=li"--></code>"#,
+    );
+    let rule = LINKEDIN.selectors.apply_url.as_ref().unwrap();
+    assert_eq!(
+        extract_from_rule(&page, rule).as_deref(),
+        Some("https://jobs.acme.example/apply/4821?src=li")
+    );
+}
+
+fn linkedin_card(title: &str, company: &str, location: &str) -> Html {
+    Html::parse_fragment(&format!(
+        r#"<div class="base-search-card">
+            <h3 class="base-search-card__title">{}</h3>
+            <h4 class="base-search-card__subtitle"><a>{}</a></h4>
+            <span class="job-search-card__location">{}</span>
+        </div>"#,
+        title, company, location
+    ))
+}
+
+#[test]
+fn test_radius_keeps_nearby_cards_and_flags_unlocated_ones() {
+    let mut params = JobSearchParams {
+        radius: Some(Radius::new("Lyon", 20.0)),
+        ..JobSearchParams::default()
+    };
+    let check = |params: &JobSearchParams, location: &str| {
+        check_card(
+            &linkedin_card("Développeur Rust", "Acme", location),
+            &LINKEDIN.selectors,
+            params,
+        )
+    };
+    assert_eq!(
+        check(&params, "Villeurbanne, Auvergne-Rhône-Alpes"),
+        CardCheck::Keep
+    );
+    assert_eq!(
+        check(&params, "Marseille, Provence-Alpes-Côte d'Azur"),
+        CardCheck::Reject
+    );
+    assert_eq!(
+        check(&params, "Quelque part"),
+        CardCheck::Unlocated { kept: true }
+    );
+
+    params.radius.as_mut().unwrap().keep_unlocated = false;
+    let unlocated = check(&params, "Quelque part");
+    assert_eq!(unlocated, CardCheck::Unlocated { kept: false });
+    assert!(!unlocated.is_kept());
+}
+
+#[test]
+fn test_radius_center_is_resolved_once() {
+    let radius = Radius::new("Lyon", 20.0);
+    assert!(radius.coordinates.is_some());
+    assert!(radius.keep_unlocated);
+    assert!(Radius::new("Atlantide", 20.0).coordinates.is_none());
+}
+
+#[test]
+fn test_title_and_company_filters_reject_cards_before_the_job_page() {
+    let mut params = JobSearchParams::default();
+    params
+        .keywords
+        .field_mut(KeywordField::Title)
+        .must_not_contain
+        .push("stage".to_string());
+    params.companies.deny.push("Capgemini".to_string());
+    let check = |title: &str, company: &str| {
+        check_card(
+            &linkedin_card(title, company, "Lyon"),
+            &LINKEDIN.selectors,
+            &params,
+        )
+    };
+
+    assert_eq!(check("Développeur Rust", "Doctolib"), CardCheck::Keep);
+    assert_eq!(
+        check("Stage - Développeur Rust", "Doctolib"),
+        CardCheck::Reject
+    );
+    assert_eq!(
+        check("Développeur Rust", "Capgemini Engineering"),
+        CardCheck::Reject
+    );
+}
+
+#[test]
+fn test_pagination_stops_on_repeated_pages_and_at_the_cap() {
+    let mut pagination = Pagination::new(20);
+    let page = |pagination: &mut Pagination, ids: &[&str]| {
+        let new_cards = ids.iter().filter(|id| pagination.is_new(id)).count();
+        pagination.next_page(new_cards)
+    };
+    assert!(page(&mut pagination, &["1", "2", "3"]));
+    assert!(page(&mut pagination, &["3", "4"]));
+    assert!(!page(&mut pagination, &["3", "4"]));
+
+    let mut pagination = Pagination::new(3);
+    assert!(page(&mut pagination, &["a"]));
+    assert!(page(&mut pagination, &["b"]));
+    assert!(!page(&mut pagination, &["c"]));
+    assert_eq!(JobSearchParams::default().max_pages, 20);
+}
+
+#[test]
+fn test_empty_required_fields_are_failures() {
+    let job = Job {
+        title: "Développeur Rust".to_string(),
+        company: "Acme".to_string(),
+        description: "Une API en Rust.".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(missing_field(&job), None);
+    assert_eq!(
+        missing_field(&Job {
+            title: " ".to_string(),
+            ..job.clone()
+        }),
+        Some("title")
+    );
+    assert_eq!(
+        missing_field(&Job {
+            company: String::new(),
+            ..job.clone()
+        }),
+        Some("company")
+    );
+    assert_eq!(
+        missing_field(&Job {
+            description: String::new(),
+            ..job
+        }),
+        Some("description")
+    );
+}
+
+#[test]
+fn test_single_board_search_reports_card_failures() {
+    let failure = || ScraperError::ExtractionFailed {
+        board: "Hellowork".to_string(),
+        url: "https://example.com/offre/1".to_string(),
+        field: "title".to_string(),
+        message: "empty".to_string(),
+    };
+    let report = SearchReport {
+        card_errors: vec![failure()],
+        ..Default::default()
+    };
+    assert!(matches!(
+        report.into_result(false),
+        Err(ScraperError::ExtractionFailed { field, .. }) if field == "title"
+    ));
+
+    let report = SearchReport {
+        card_errors: vec![failure()],
+        ..Default::default()
+    };
+    assert!(report.into_result(true).unwrap().is_empty());
+    assert!(SearchReport::default()
+        .into_result(false)
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,41 @@
+use scraper::Html;
+use scraptain::board::posting_links;
+use scraptain::{CareerSite, ScraperError};
+
+#[test]
+fn test_posting_links_are_resolved_and_deduplicated() {
+    let site = CareerSite::new(
+        "Acme",
+        "https://careers.acme.fr/fr/offres?page=1",
+        "ul.jobs a.job-link",
+    );
+    let page = Html::parse_document(
+        r##"
+        <ul class="jobs">
+            <li><a class="job-link" href="/fr/offres/42-dev-rust">Dev Rust</a></li>
+            <li><a class="job-link" href="/fr/offres/42-dev-rust#apply">Postuler</a></li>
+            <li><a class="job-link" href="https://jobs.example.com/acme/7">Data</a></li>
+            <li><a href="/fr/equipe">Équipe</a></li>
+        </ul>
+        "##,
+    );
+    assert_eq!(
+        posting_links(&page, &site),
+        vec![
+            "https://careers.acme.fr/fr/offres/42-dev-rust",
+            "https://jobs.example.com/acme/7",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_career_site() {
+    let site = CareerSite::new("Acme", "careers.acme.fr", "a[");
+    match site.validate() {
+        Err(ScraperError::InvalidConfig { board, problems }) => {
+            assert_eq!(board, "Acme");
+            assert_eq!(problems.len(), 2, "{:?}", problems);
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
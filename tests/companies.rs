@@ -0,0 +1,61 @@
+use scraptain::models::CompanyFilter;
+use scraptain::text::{normalize_company, same_company};
+
+#[test]
+fn test_company_names_are_normalized() {
+    assert_eq!(normalize_company("CAPGEMINI France"), "capgemini france");
+    assert_eq!(
+        normalize_company("Société Générale S.A."),
+        "societe generale"
+    );
+    assert_eq!(normalize_company("Groupe SEB"), "groupe seb");
+    assert_eq!(normalize_company("Acme Holding SAS"), "acme holding");
+    assert_eq!(normalize_company("SAS"), "sas");
+
+    assert!(same_company("Capgemini Engineering", "Capgemini"));
+    assert!(same_company("CAPGEMINI France", "capgemini"));
+    assert!(!same_company("Capgemini Invent", "Capgemini Engineering"));
+    assert!(!same_company("Alten", "Altendorf"));
+    assert!(!same_company("Altendorf GmbH", "Alten"));
+}
+
+#[test]
+fn test_only_trailing_legal_forms_are_stripped() {
+    assert_eq!(normalize_company("Air France"), "air france");
+    assert_eq!(normalize_company("France Travail"), "france travail");
+    assert_eq!(normalize_company("SA Acme"), "sa acme");
+
+    assert!(!same_company("Air Liquide", "Air France"));
+    assert!(!same_company("Air France", "Air Liquide"));
+    assert!(!same_company("France Travail", "Travail Temporaire"));
+    assert!(same_company("Air France KLM", "Air France"));
+
+    let deny = CompanyFilter {
+        deny: vec!["Air France".to_string()],
+        ..Default::default()
+    };
+    assert!(deny.matches("Air Liquide"));
+    assert!(!deny.matches("AIR FRANCE SA"));
+}
+
+#[test]
+fn test_allow_and_deny_lists() {
+    let deny = CompanyFilter {
+        deny: vec!["Capgemini".to_string(), "Acme SAS".to_string()],
+        ..Default::default()
+    };
+    assert!(!deny.matches("Capgemini Engineering"));
+    assert!(!deny.matches("ACME"));
+    assert!(deny.matches("Doctolib"));
+
+    let allow = CompanyFilter {
+        allow: vec!["Doctolib".to_string(), "Mistral AI".to_string()],
+        deny: vec!["Doctolib Recrutement".to_string()],
+    };
+    assert!(allow.matches("DOCTOLIB SAS"));
+    assert!(allow.matches("Mistral AI"));
+    assert!(!allow.matches("Mistral"));
+    assert!(!allow.matches("Doctolib Recrutement"));
+    assert!(!allow.matches(""));
+    assert!(CompanyFilter::default().is_empty());
+}
@@ -0,0 +1,116 @@
+use scraptain::actions::PageAction;
+use scraptain::constants::{BoardConfig, HELLOWORK, LINKEDIN, WTTJ};
+use scraptain::models::{Rule, RuleReturns, Selectors, Selects};
+use scraptain::transforms::Transform;
+use scraptain::ScraperError;
+use std::borrow::Cow;
+
+#[test]
+fn test_builtin_configs_are_valid() {
+    for config in [HELLOWORK, LINKEDIN, WTTJ] {
+        assert!(config.validate().is_ok(), "{} is invalid", config.name);
+    }
+}
+
+#[test]
+fn test_invalid_config_lists_every_problem() {
+    let config = BoardConfig {
+        base_url: "not a url",
+        job_path: "/jobs/",
+        selectors: Selectors {
+            title: Rule {
+                selects: Selects::Css("h3["),
+                n: None,
+                returns: RuleReturns::Text,
+                transforms: Cow::Borrowed(&[]),
+                alternatives: &[],
+            },
+            description: Rule {
+                selects: Selects::Css("div p"),
+                n: Some((3, 1)),
+                returns: RuleReturns::Text,
+                transforms: Cow::Borrowed(&[]),
+                alternatives: &[],
+            },
+            ..HELLOWORK.selectors
+        },
+        ..HELLOWORK
+    };
+
+    match config.validate() {
+        Err(ScraperError::InvalidConfig { board, problems }) => {
+            assert_eq!(board, "Hellowork");
+            assert_eq!(problems.len(), 4, "{:?}", problems);
+            assert!(problems[0].contains("base_url"));
+            assert!(problems[1].contains("job_path"));
+            assert!(problems[2].contains("`title`"));
+            assert!(problems[3].contains("`description`"));
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_alternative_selectors_are_validated() {
+    let config = BoardConfig {
+        selectors: Selectors {
+            company: Rule {
+                selects: Selects::Css("span.company"),
+                n: None,
+                returns: RuleReturns::Text,
+                transforms: Cow::Borrowed(&[]),
+                alternatives: &[Rule {
+                    selects: Selects::Css("span..company"),
+                    n: None,
+                    returns: RuleReturns::Text,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                }],
+            },
+            ..HELLOWORK.selectors
+        },
+        ..HELLOWORK
+    };
+
+    match config.validate() {
+        Err(ScraperError::InvalidConfig { problems, .. }) => {
+            assert_eq!(problems.len(), 1, "{:?}", problems);
+            assert!(problems[0].contains("span..company"));
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_transform_pipelines_are_validated() {
+    let config = BoardConfig {
+        selectors: Selectors {
+            id: Rule {
+                transforms: Cow::Borrowed(&[Transform::RegexCapture {
+                    pattern: Cow::Borrowed("(\\d+"),
+                    group: 1,
+                }]),
+                ..HELLOWORK.selectors.id
+            },
+            ..HELLOWORK.selectors
+        },
+        ..HELLOWORK
+    };
+
+    match config.validate() {
+        Err(ScraperError::InvalidConfig { problems, .. }) => {
+            assert_eq!(problems.len(), 1, "{:?}", problems);
+            assert!(problems[0].contains("`id`"));
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_configs_accept_page_actions() {
+    let config = BoardConfig {
+        board_page_action: Some(PageAction::new_async(|_| Box::pin(async { Ok(()) }))),
+        ..LINKEDIN
+    };
+    assert!(config.validate().is_ok());
+}
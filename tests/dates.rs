@@ -0,0 +1,109 @@
+use chrono::{DateTime, TimeZone, Utc};
+use scraptain::dates::{parse_date_label, DatePrecision, FixedClock};
+
+fn clock() -> FixedClock {
+    FixedClock(Utc.with_ymd_and_hms(2025, 3, 20, 15, 30, 0).unwrap())
+}
+
+fn parse(text: &str) -> (DateTime<Utc>, DatePrecision) {
+    let parsed = parse_date_label(text, &clock()).unwrap_or_else(|| panic!("{:?}", text));
+    (parsed.datetime, parsed.precision)
+}
+
+fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+#[test]
+fn test_relative_french_labels() {
+    use DatePrecision::*;
+    assert_eq!(
+        parse("il y a 3 heures"),
+        (utc(2025, 3, 20, 12, 30), Estimated)
+    );
+    assert_eq!(
+        parse("Publiée il y a 2 jours"),
+        (utc(2025, 3, 18, 0, 0), Estimated)
+    );
+    assert_eq!(parse("1 semaine"), (utc(2025, 3, 13, 0, 0), Estimated));
+    assert_eq!(parse("il y a 2 mois"), (utc(2025, 1, 20, 0, 0), Estimated));
+    assert_eq!(parse("il y a un an"), (utc(2024, 3, 20, 0, 0), Estimated));
+    assert_eq!(
+        parse("Il y a plus de 30 jours"),
+        (utc(2025, 2, 18, 0, 0), Estimated)
+    );
+    assert_eq!(parse("hier"), (utc(2025, 3, 19, 0, 0), Day));
+    assert_eq!(parse("avant-hier"), (utc(2025, 3, 18, 0, 0), Day));
+    assert_eq!(parse("Aujourd’hui"), (utc(2025, 3, 20, 0, 0), Day));
+    assert_eq!(parse("À l'instant"), (utc(2025, 3, 20, 15, 30), Estimated));
+}
+
+#[test]
+fn test_relative_english_labels() {
+    use DatePrecision::*;
+    assert_eq!(parse("3 days ago"), (utc(2025, 3, 17, 0, 0), Estimated));
+    assert_eq!(parse("an hour ago"), (utc(2025, 3, 20, 14, 30), Estimated));
+    assert_eq!(parse("30+ days ago"), (utc(2025, 2, 18, 0, 0), Estimated));
+    assert_eq!(parse("Just now"), (utc(2025, 3, 20, 15, 30), Estimated));
+    assert_eq!(parse("Yesterday"), (utc(2025, 3, 19, 0, 0), Day));
+}
+
+#[test]
+fn test_absolute_dates() {
+    use DatePrecision::*;
+    assert_eq!(
+        parse("Publiée le 12 mars 2025"),
+        (utc(2025, 3, 12, 0, 0), Day)
+    );
+    assert_eq!(
+        parse("Publiée le 12 mars 2025 à 10h"),
+        (utc(2025, 3, 12, 0, 0), Day)
+    );
+    assert_eq!(parse("le 1er février"), (utc(2025, 2, 1, 0, 0), Day));
+    assert_eq!(
+        parse("Publiée le 2 décembre"),
+        (utc(2024, 12, 2, 0, 0), Day)
+    );
+    assert_eq!(parse("March 12, 2025"), (utc(2025, 3, 12, 0, 0), Day));
+    assert_eq!(parse("12/03/2025"), (utc(2025, 3, 12, 0, 0), Day));
+    assert_eq!(parse("2025-03-12"), (utc(2025, 3, 12, 0, 0), Day));
+}
+
+#[test]
+fn test_timestamps() {
+    use DatePrecision::*;
+    assert_eq!(
+        parse("2025-03-12T10:00:00Z"),
+        (utc(2025, 3, 12, 10, 0), Exact)
+    );
+    assert_eq!(parse("2025-03-12T10:00:00.123+02:00").1, Exact);
+    assert_eq!(
+        parse("2025-03-12T10:00:00.123+02:00").0.timestamp(),
+        utc(2025, 3, 12, 8, 0).timestamp()
+    );
+    assert_eq!(
+        parse("2025-03-12T10:00:00"),
+        (utc(2025, 3, 12, 10, 0), Exact)
+    );
+}
+
+#[test]
+fn test_unparseable_labels() {
+    assert!(parse_date_label("", &clock()).is_none());
+    assert!(parse_date_label("Nouveau", &clock()).is_none());
+    assert!(parse_date_label("CDI - Lyon", &clock()).is_none());
+}
+
+#[test]
+fn test_huge_relative_counts_do_not_overflow() {
+    for label in [
+        "il y a 99999999999 minutes",
+        "il y a 3000000000 heures",
+        "il y a 100000000 jours",
+        "il y a 20000000 semaines",
+        "il y a 4000000000 mois",
+        "il y a 400000000 ans",
+    ] {
+        assert!(parse_date_label(label, &clock()).is_none(), "{}", label);
+    }
+}
@@ -0,0 +1,127 @@
+use scraper::Html;
+use scraptain::board::extract_description;
+use scraptain::constants::{HELLOWORK, WTTJ};
+use scraptain::dates::SystemClock;
+use scraptain::models::{Rule, RuleReturns, Selectors, Selects};
+use std::borrow::Cow;
+
+#[test]
+fn test_sections_are_named_and_description_is_kept_whole() {
+    let page = Html::parse_document(
+        r#"
+        <div id="offer-panel">
+            <section><h2>Les missions</h2><ul><li>Concevoir l'API</li><li>Relire le code</li></ul></section>
+            <section><h2>Le profil</h2><p>5 ans d'expérience.</p></section>
+            <section><h2>Avantages</h2><p>RTT et tickets restaurant.</p></section>
+            <section><h2>Process</h2><p>Deux entretiens.</p></section>
+        </div>
+        "#,
+    );
+    let (description, sections) =
+        extract_description(&page, &HELLOWORK.selectors, &SystemClock, |_, _, _| {});
+
+    assert_eq!(sections.len(), 3);
+    assert_eq!(
+        sections["missions"],
+        "## Les missions\n\n- Concevoir l'API\n- Relire le code"
+    );
+    assert_eq!(sections["profil"], "## Le profil\n\n5 ans d'expérience.");
+    assert!(description.starts_with(&sections["missions"]));
+    assert!(description.ends_with("## Process\n\nDeux entretiens."));
+}
+
+#[test]
+fn test_sections_are_found_by_heading() {
+    let page = Html::parse_document(
+        r#"
+        <div id="offer-panel">
+            <section><h2>Avantages</h2><p>RTT.</p></section>
+            <section><h2>Process</h2><p>Deux entretiens.</p></section>
+            <section><h2>Le profil recherché</h2><p>Rust.</p></section>
+        </div>
+        "#,
+    );
+    let (_, sections) =
+        extract_description(&page, &HELLOWORK.selectors, &SystemClock, |_, _, _| {});
+
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections["avantages"], "## Avantages\n\nRTT.");
+    assert_eq!(sections["profil"], "## Le profil recherché\n\nRust.");
+}
+
+#[test]
+fn test_description_rule_is_used_without_sections() {
+    let page = Html::parse_document(
+        r#"<div id="the-position-section"><p>Un seul bloc de texte.</p></div>"#,
+    );
+    let (description, sections) =
+        extract_description(&page, &WTTJ.selectors, &SystemClock, |_, _, _| {});
+
+    assert!(sections.is_empty());
+    assert_eq!(description, "Un seul bloc de texte.");
+}
+
+#[test]
+fn test_alternative_selectors_are_tried_in_order() {
+    let selectors = Selectors {
+        description: Rule {
+            selects: Selects::Css("div.description-v2"),
+            n: None,
+            returns: RuleReturns::Markdown,
+            transforms: Cow::Borrowed(&[]),
+            alternatives: &[
+                Rule {
+                    selects: Selects::Css("div.description"),
+                    n: None,
+                    returns: RuleReturns::Markdown,
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+                Rule {
+                    selects: Selects::Css("meta[name='description']"),
+                    n: None,
+                    returns: RuleReturns::Attribute("content"),
+                    transforms: Cow::Borrowed(&[]),
+                    alternatives: &[],
+                },
+            ],
+        },
+        sections: &[],
+        ..WTTJ.selectors
+    };
+    let mut matches = Vec::new();
+    let mut extract = |html: &str| {
+        let page = Html::parse_document(html);
+        extract_description(
+            &page,
+            &selectors,
+            &SystemClock,
+            |field, alternative, rule| {
+                matches.push((field.to_string(), alternative, rule.selects.as_str()))
+            },
+        )
+        .0
+    };
+
+    assert_eq!(
+        extract(r#"<div class="description-v2"><p>Nouveau</p></div>"#),
+        "Nouveau"
+    );
+    assert_eq!(
+        extract(r#"<div class="description"><p>Ancien</p></div>"#),
+        "Ancien"
+    );
+    assert_eq!(
+        extract(r#"<meta name="description" content="Résumé"><div class="description"></div>"#),
+        "Résumé"
+    );
+    assert_eq!(extract("<p>Rien</p>"), "");
+    assert_eq!(
+        matches,
+        vec![
+            ("description".to_string(), 0, "div.description-v2"),
+            ("description".to_string(), 1, "div.description"),
+            ("description".to_string(), 2, "meta[name='description']"),
+        ]
+    );
+}
@@ -0,0 +1,128 @@
+use scraptain::experience::{
+    infer, level_from_description, level_from_label, years_in_text, YearsRange,
+};
+use scraptain::ExperienceLevel;
+
+#[test]
+fn test_board_labels() {
+    assert_eq!(
+        level_from_label("Mid-Senior level"),
+        Some(ExperienceLevel::Mid)
+    );
+    assert_eq!(
+        level_from_label("Entry level"),
+        Some(ExperienceLevel::Junior)
+    );
+    assert_eq!(
+        level_from_label("Stage de fin d'études"),
+        Some(ExperienceLevel::Internship)
+    );
+    assert_eq!(level_from_label("Director"), Some(ExperienceLevel::Lead));
+    assert_eq!(level_from_label("Développeur Rust"), None);
+
+    assert_eq!(
+        infer(Some("3 à 5 ans"), "Développeur Rust", ""),
+        (
+            Some(ExperienceLevel::Mid),
+            Some(YearsRange {
+                min: 3,
+                max: Some(5)
+            })
+        )
+    );
+    assert_eq!(
+        infer(Some("Débutant accepté"), "Développeur Rust Senior", ""),
+        (Some(ExperienceLevel::Junior), None)
+    );
+}
+
+#[test]
+fn test_inferred_from_title_and_description() {
+    let description = "Vous avez au moins 5 ans d'expérience en développement backend.";
+    assert_eq!(
+        infer(None, "Lead Developer Rust (H/F)", description),
+        (
+            Some(ExperienceLevel::Lead),
+            Some(YearsRange { min: 5, max: None })
+        )
+    );
+    assert_eq!(
+        infer(None, "Développeur Rust", description).0,
+        Some(ExperienceLevel::Senior)
+    );
+    assert_eq!(
+        infer(
+            None,
+            "Développeur Rust",
+            "Profil junior bienvenu, équipe créée il y a 3 ans."
+        )
+        .0,
+        Some(ExperienceLevel::Junior)
+    );
+    assert_eq!(
+        infer(None, "Développeur Rust confirmé", "").0,
+        Some(ExperienceLevel::Mid)
+    );
+    assert_eq!(infer(None, "Développeur Rust", ""), (None, None));
+}
+
+#[test]
+fn test_years_need_experience_context() {
+    assert_eq!(
+        years_in_text("Expérience : 2-4 years minimum"),
+        Some(YearsRange {
+            min: 2,
+            max: Some(4)
+        })
+    );
+    assert_eq!(
+        years_in_text("3+ years of professional experience"),
+        Some(YearsRange { min: 3, max: None })
+    );
+    assert_eq!(years_in_text("Entreprise fondée il y a 10 ans."), None);
+    assert_eq!(years_in_text("CDI, 5 à 3 ans d'expérience"), None);
+}
+
+#[test]
+fn test_generic_nouns_need_a_role() {
+    assert_eq!(level_from_label("Account Manager"), None);
+    assert_eq!(level_from_label("Account Executive"), None);
+    assert_eq!(level_from_label("Staff Nurse"), None);
+    assert_eq!(level_from_label("Executive"), Some(ExperienceLevel::Lead));
+    assert_eq!(
+        level_from_label("Engineering Manager"),
+        Some(ExperienceLevel::Lead)
+    );
+    assert_eq!(
+        level_from_label("Staff Software Engineer"),
+        Some(ExperienceLevel::Lead)
+    );
+    assert_eq!(
+        level_from_label("Lead Backend Developer"),
+        Some(ExperienceLevel::Lead)
+    );
+}
+
+#[test]
+fn test_description_needs_an_explicit_phrase() {
+    assert_eq!(
+        infer(
+            None,
+            "Développeur Rust",
+            "Vous travaillerez avec le tech lead et l'engineering manager."
+        ),
+        (None, None)
+    );
+    assert_eq!(
+        level_from_description("Ce poste n'est pas ouvert : pas d'alternance ni de stage."),
+        None
+    );
+    assert_eq!(
+        level_from_description("Nous recherchons un profil senior."),
+        Some(ExperienceLevel::Senior)
+    );
+    assert_eq!(
+        level_from_description("Poste de stagiaire de 6 mois."),
+        Some(ExperienceLevel::Internship)
+    );
+}
@@ -0,0 +1,131 @@
+use scraper::Html;
+use scraptain::board::extract_facts;
+use scraptain::constants::{HELLOWORK, LINKEDIN, WTTJ};
+use scraptain::dates::SystemClock;
+use scraptain::facts::{canonical, key_values, parse_salary};
+use scraptain::models::{JobField, Salary, Selectors, Selects};
+
+fn block_pairs(html: &str) -> Vec<(String, String)> {
+    let document = Html::parse_fragment(html);
+    let block = Selects::Css("#facts").select(&document).unwrap()[0];
+    key_values(block)
+}
+
+#[test]
+fn test_key_value_layouts() {
+    let expected = vec![
+        ("Contrat".to_string(), "CDI".to_string()),
+        ("Salaire".to_string(), "45 - 55 k€".to_string()),
+    ];
+    assert_eq!(
+        block_pairs(
+            r#"<dl id="facts"><dt>Contrat</dt><dd>CDI</dd><dt>Salaire :</dt><dd>45 - 55 k€</dd></dl>"#
+        ),
+        expected
+    );
+    assert_eq!(
+        block_pairs(
+            r#"<table id="facts"><tr><th>Contrat</th><td>CDI</td></tr><tr><th>Salaire</th><td>45 - 55 k€</td></tr></table>"#
+        ),
+        expected
+    );
+    assert_eq!(
+        block_pairs(
+            r#"<ul id="facts">
+                <li><span class="label">Contrat</span><span>CDI</span></li>
+                <li>Salaire : 45 - 55 k€</li>
+                <li>Rust</li>
+            </ul>"#
+        ),
+        expected
+    );
+    assert_eq!(
+        block_pairs(
+            r#"<div id="facts"><p><strong>Contrat</strong> CDI</p><p>Salaire: 45 - 55 k€</p></div>"#
+        ),
+        expected
+    );
+}
+
+#[test]
+fn test_labels_map_to_canonical_fields() {
+    assert_eq!(canonical(" Expérience ", &[]), Some(JobField::Experience));
+    assert_eq!(canonical("Type d’emploi", &[]), Some(JobField::Contract));
+    assert_eq!(
+        canonical("Seniority level", LINKEDIN.selectors.labels),
+        Some(JobField::Experience)
+    );
+    assert_eq!(canonical("Seniority level", &[]), None);
+    assert_eq!(
+        canonical("Secteurs", LINKEDIN.selectors.labels),
+        Some(JobField::CompanySector)
+    );
+    assert_eq!(canonical("Fonction", LINKEDIN.selectors.labels), None);
+
+    let page = Html::parse_document(
+        r#"<ul class="description__job-criteria-list">
+            <li><h3>Seniority level</h3><span>Mid-Senior level</span></li>
+            <li><h3>Employment type</h3><span>Full-time</span></li>
+            <li><h3>Industries</h3><span>Software Development</span></li>
+            <li><h3>Job function</h3><span>Engineering</span></li>
+        </ul>"#,
+    );
+    let mut matched = Vec::new();
+    let facts = extract_facts(
+        &page,
+        &LINKEDIN.selectors,
+        &SystemClock,
+        |field, alternative, _| matched.push((field.to_string(), alternative)),
+    );
+    assert_eq!(facts["experience"], "Mid-Senior level");
+    assert_eq!(facts["contract"], "Full-time");
+    assert_eq!(facts["company_sector"], "Software Development");
+    assert_eq!(facts["Job function"], "Engineering");
+    assert_eq!(matched, vec![("facts".to_string(), 0)]);
+
+    let selectors = Selectors {
+        facts: None,
+        ..WTTJ.selectors
+    };
+    assert!(extract_facts(&page, &selectors, &SystemClock, |_, _, _| {}).is_empty());
+    assert!(extract_facts(&page, &HELLOWORK.selectors, &SystemClock, |_, _, _| {}).is_empty());
+}
+
+#[test]
+fn test_labels_and_values_keep_their_colons() {
+    let page = Html::parse_document(
+        r#"<section><h2>Informations</h2><dl>
+            <dt>Salaire</dt><dd>40k: 45k</dd>
+            <dt>Note: interne</dt><dd>oui</dd>
+        </dl></section>"#,
+    );
+    let facts = extract_facts(&page, &HELLOWORK.selectors, &SystemClock, |_, _, _| {});
+    assert_eq!(facts["salary"], "40k: 45k");
+    assert_eq!(facts["Note: interne"], "oui");
+}
+
+#[test]
+fn test_salary_labels() {
+    assert_eq!(
+        parse_salary("45 - 55 k€"),
+        Some(Salary {
+            min: Some(45000.0),
+            max: Some(55000.0),
+            currency: Some("EUR".to_string()),
+            period: Some("YEAR".to_string()),
+        })
+    );
+    assert_eq!(
+        parse_salary("3 200 € par mois"),
+        Some(Salary {
+            min: Some(3200.0),
+            max: Some(3200.0),
+            currency: Some("EUR".to_string()),
+            period: Some("MONTH".to_string()),
+        })
+    );
+    let hourly = parse_salary("12,50 EUR / heure").unwrap();
+    assert_eq!(hourly.min, Some(12.5));
+    assert_eq!(hourly.period.as_deref(), Some("HOUR"));
+    assert_eq!(parse_salary("Selon profil"), None);
+}
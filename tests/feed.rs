@@ -0,0 +1,98 @@
+use chrono::{TimeZone, Utc};
+use scraptain::dates::{DatePrecision, PostedDate};
+use scraptain::feed::{to_atom, to_rss};
+use scraptain::{FeedInfo, Job};
+
+fn sample_jobs() -> Vec<Job> {
+    vec![
+        Job {
+            id: "12345".to_string(),
+            title: "Développeur Rust <senior>".to_string(),
+            company: "Acme & Co".to_string(),
+            location: "Lyon".to_string(),
+            description: "Build scrapers.".to_string(),
+            date_posted: posted(2025, 3, 12),
+            url: "https://example.com/jobs/12345?a=1&b=2".to_string(),
+            source: "Hellowork".to_string(),
+            ..Default::default()
+        },
+        Job {
+            id: "678".to_string(),
+            title: "Data engineer".to_string(),
+            date_posted: posted(2025, 3, 14),
+            source: "Linkedin".to_string(),
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn test_atom_feed() {
+    let xml = to_atom(&sample_jobs(), &FeedInfo::default());
+
+    assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+    assert!(xml.contains("<updated>2025-03-14T00:00:00+00:00</updated>"));
+    assert!(xml.contains("<id>urn:scraptain:Hellowork:12345</id>"));
+    assert!(xml.contains("<title>Développeur Rust &lt;senior&gt;</title>"));
+    assert!(xml.contains("<name>Acme &amp; Co</name>"));
+    assert!(xml.contains("href=\"https://example.com/jobs/12345?a=1&amp;b=2\""));
+    assert!(xml.contains("<name>Linkedin</name>"));
+    assert_eq!(xml.matches("<entry>").count(), 2);
+}
+
+#[test]
+fn test_rss_feed() {
+    let xml = to_rss(&sample_jobs(), &FeedInfo::default());
+
+    assert!(xml.contains("<rss version=\"2.0\">"));
+    assert!(xml.contains("<guid isPermaLink=\"false\">urn:scraptain:Linkedin:678</guid>"));
+    assert!(xml.contains("<pubDate>Wed, 12 Mar 2025 00:00:00 +0000</pubDate>"));
+    assert!(xml.contains("<description>Lyon\n\nBuild scrapers.</description>"));
+    assert_eq!(xml.matches("<item>").count(), 2);
+}
+
+fn posted(year: i32, month: u32, day: u32) -> PostedDate {
+    PostedDate {
+        datetime: Some(Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()),
+        precision: DatePrecision::Day,
+    }
+}
+
+#[test]
+fn test_undated_entries_use_the_feed_date() {
+    let mut jobs = sample_jobs();
+    jobs.push(Job {
+        id: "9".to_string(),
+        title: "Undated".to_string(),
+        source: "WelcomeToTheJungle".to_string(),
+        ..Default::default()
+    });
+    let xml = to_atom(&jobs, &FeedInfo::default());
+    assert_eq!(
+        xml.matches("<updated>2025-03-14T00:00:00+00:00</updated>")
+            .count(),
+        3
+    );
+
+    let info = FeedInfo {
+        updated: Some(Utc.with_ymd_and_hms(2025, 4, 1, 8, 0, 0).unwrap()),
+        ..Default::default()
+    };
+    let undated = &jobs[2..];
+    assert_eq!(to_atom(undated, &info), to_atom(undated, &info));
+    assert!(to_atom(undated, &info).contains("<updated>2025-04-01T08:00:00+00:00</updated>"));
+    assert!(to_atom(undated, &FeedInfo::default())
+        .contains("<updated>1970-01-01T00:00:00+00:00</updated>"));
+}
+
+#[test]
+fn test_rss_omits_an_empty_channel_link() {
+    let xml = to_rss(&sample_jobs(), &FeedInfo::default());
+    assert!(!xml.contains("<link></link>"));
+
+    let info = FeedInfo {
+        link: "https://example.com/jobs".to_string(),
+        ..Default::default()
+    };
+    assert!(to_rss(&sample_jobs(), &info).contains("    <link>https://example.com/jobs</link>"));
+}
@@ -0,0 +1,52 @@
+use scraper::Html;
+use scraptain::constants::LINKEDIN;
+use scraptain::health::{measure_cards, BoardHealth, FieldHealth};
+
+fn card(title: &str, company: &str) -> Html {
+    Html::parse_fragment(&format!(
+        r#"<div class="base-search-card" data-entity-urn="urn:li:jobPosting:42">
+            <h3 class="base-search-card__title">{title}</h3>
+            <h4 class="base-search-card__subtitle">{company}</h4>
+            <span class="job-search-card__location">Lyon</span>
+            <time class="job-search-card__listdate" datetime="2025-03-12"></time>
+        </div>"#
+    ))
+}
+
+#[test]
+fn test_measure_cards_flags_drifted_fields() {
+    let cards = vec![card("Développeur", "Acme"), card("Data engineer", "Beta")];
+    let mut fields = measure_cards(&LINKEDIN.selectors, &cards);
+
+    let rate = |name: &str| {
+        fields
+            .iter()
+            .find(|f| f.field == name)
+            .map(|f| f.match_rate())
+            .unwrap()
+    };
+    assert_eq!(rate("id"), Some(1.0));
+    assert_eq!(rate("title"), Some(1.0));
+    assert_eq!(rate("company"), Some(0.0));
+    assert_eq!(rate("location"), Some(1.0));
+    assert_eq!(rate("date_posted"), Some(1.0));
+
+    fields.push(FieldHealth {
+        field: "description",
+        selector: "div.show-more-less-html__markup".to_string(),
+        matched: 0,
+        total: 0,
+        sample: None,
+    });
+    assert_eq!(fields.last().unwrap().match_rate(), None);
+
+    let health = BoardHealth {
+        board: "Linkedin".to_string(),
+        url: String::new(),
+        fields,
+    };
+    let drifted = health.drifted(0.8);
+    assert_eq!(drifted.len(), 1);
+    assert_eq!(drifted[0].field, "company");
+    assert_eq!(drifted[0].selector, "h4.base-search-card__subtitle a");
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "search-index")]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use scraptain::dates::{DatePrecision, PostedDate};
+use scraptain::index::{IndexQuery, JobIndex};
+use scraptain::Job;
+
+fn job(id: &str, title: &str, source: &str, contract: &str, day: u32) -> Job {
+    Job {
+        id: id.to_string(),
+        title: title.to_string(),
+        company: "Acme".to_string(),
+        location: "Lyon".to_string(),
+        description: "Équipe produit à Lyon".to_string(),
+        date_posted: posted(2025, 3, day),
+        source: source.to_string(),
+        contract: Some(contract.to_string()),
+        ..Default::default()
+    }
+}
+
+fn sample_index() -> JobIndex {
+    let mut index = JobIndex::in_memory().unwrap();
+    index
+        .add_jobs(&[
+            job("1", "Développeur Rust", "Hellowork", "CDI", 10),
+            job("2", "Développeuse Java", "Linkedin", "CDD", 12),
+            job("3", "Chef de projet", "Hellowork", "CDI", 14),
+        ])
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_accent_folding_and_stemming() {
+    let index = sample_index();
+    let jobs = index
+        .search(&IndexQuery {
+            text: "developpeur".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, "1");
+
+    let jobs = index
+        .search(&IndexQuery {
+            text: "equipe".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(jobs.len(), 3);
+}
+
+#[test]
+fn test_filters() {
+    let index = sample_index();
+    let jobs = index
+        .search(&IndexQuery {
+            sources: vec!["Hellowork".to_string()],
+            contracts: vec!["cdi".to_string()],
+            posted_after: NaiveDate::from_ymd_opt(2025, 3, 11),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, "3");
+}
+
+#[test]
+fn test_reindexing_replaces_jobs() {
+    let mut index = sample_index();
+    index
+        .add_jobs(&[job("1", "Développeur Go", "Hellowork", "CDI", 10)])
+        .unwrap();
+    assert_eq!(index.len(), 3);
+}
+
+fn posted(year: i32, month: u32, day: u32) -> PostedDate {
+    PostedDate {
+        datetime: Some(Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()),
+        precision: DatePrecision::Day,
+    }
+}
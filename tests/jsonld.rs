@@ -0,0 +1,92 @@
+use chrono::{TimeZone, Utc};
+use scraper::Html;
+use scraptain::dates::{DatePrecision, FixedClock};
+use scraptain::jsonld::extract_job_posting;
+use scraptain::Salary;
+
+fn clock() -> FixedClock {
+    FixedClock(Utc.with_ymd_and_hms(2025, 3, 20, 15, 30, 0).unwrap())
+}
+
+#[test]
+fn test_job_posting_fields() {
+    let page = Html::parse_document(
+        r#"
+        <script type="application/ld+json">{"@type": "BreadcrumbList"}</script>
+        <script type="application/ld+json">
+        {
+            "@context": "https://schema.org",
+            "@graph": [{
+                "@type": "JobPosting",
+                "title": "Développeur Rust H/F",
+                "description": "&lt;p&gt;Rejoignez &lt;b&gt;notre équipe&lt;/b&gt;.&lt;/p&gt;",
+                "datePosted": "2025-03-14",
+                "validThrough": "2025-05-14T23:59:59+02:00",
+                "employmentType": ["FULL_TIME", "CDI"],
+                "hiringOrganization": {
+                    "@type": "Organization",
+                    "name": "Acme",
+                    "sameAs": "https://acme.example",
+                    "logo": {"@type": "ImageObject", "url": "https://acme.example/logo.png"}
+                },
+                "jobLocation": [{
+                    "@type": "Place",
+                    "address": {"addressLocality": "Lyon", "postalCode": "69003", "addressCountry": "FR"}
+                }],
+                "baseSalary": {
+                    "@type": "MonetaryAmount",
+                    "currency": "EUR",
+                    "value": {"@type": "QuantitativeValue", "minValue": "45000", "maxValue": 55000, "unitText": "YEAR"}
+                }
+            }]
+        }
+        </script>
+        "#,
+    );
+    let posting = extract_job_posting(&page, &clock()).unwrap();
+
+    assert_eq!(posting.title.as_deref(), Some("Développeur Rust H/F"));
+    assert_eq!(
+        posting.description.as_deref(),
+        Some("Rejoignez **notre équipe**.")
+    );
+    assert_eq!(posting.company.as_deref(), Some("Acme"));
+    assert_eq!(posting.company_url.as_deref(), Some("https://acme.example"));
+    assert_eq!(
+        posting.company_logo.as_deref(),
+        Some("https://acme.example/logo.png")
+    );
+    assert_eq!(posting.location.as_deref(), Some("Lyon, 69003"));
+    assert_eq!(posting.employment_type.as_deref(), Some("FULL_TIME, CDI"));
+
+    let posted = posting.date_posted.unwrap();
+    assert_eq!(
+        posted.datetime,
+        Utc.with_ymd_and_hms(2025, 3, 14, 0, 0, 0).unwrap()
+    );
+    assert_eq!(posted.precision, DatePrecision::Day);
+    assert_eq!(
+        posting.valid_through.unwrap().datetime,
+        Utc.with_ymd_and_hms(2025, 5, 14, 21, 59, 59).unwrap()
+    );
+    assert_eq!(
+        posting.salary,
+        Some(Salary {
+            min: Some(45000.0),
+            max: Some(55000.0),
+            currency: Some("EUR".to_string()),
+            period: Some("YEAR".to_string()),
+        })
+    );
+}
+
+#[test]
+fn test_pages_without_job_posting() {
+    let page = Html::parse_document(
+        r#"
+        <script type="application/ld+json">{"@type": "Organization", "name": "Acme"}</script>
+        <script type="application/ld+json">{ not json </script>
+        "#,
+    );
+    assert_eq!(extract_job_posting(&page, &clock()), None);
+}
@@ -0,0 +1,53 @@
+use scraptain::models::{KeywordFilter, KeywordFilters};
+use scraptain::{Job, KeywordField};
+
+fn filter(must: &[&str], must_not: &[&str]) -> KeywordFilter {
+    KeywordFilter {
+        must_contain: must.iter().map(|k| k.to_string()).collect(),
+        must_not_contain: must_not.iter().map(|k| k.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_accent_and_case_insensitive_words() {
+    let title = filter(&["developpeur", "RUST"], &["stage", "alternance"]);
+    assert!(title.matches("Développeur Rust H/F"));
+    assert!(title.matches("DÉVELOPPEUR BACKEND (Rust, Go)"));
+    assert!(!title.matches("Développeur Java"));
+    assert!(!title.matches("Stage - Développeur Rust"));
+    assert!(!title.matches("Développeur Rust en Alternance"));
+    assert!(title.matches("Développeur Rust, mise en place de stages CI"));
+    assert!(!filter(&["rust"], &[]).matches("Développeur Trustworthy AI"));
+    assert!(filter(&["c++"], &[]).matches("Ingénieur C++ embarqué"));
+    assert!(KeywordFilter::default().matches("anything"));
+}
+
+#[test]
+fn test_filters_apply_per_field() {
+    let mut filters = KeywordFilters::default();
+    filters
+        .field_mut(KeywordField::Company)
+        .must_not_contain
+        .push("Interim".to_string());
+    filters
+        .field_mut(KeywordField::Description)
+        .must_contain
+        .push("télétravail".to_string());
+    assert!(filters.field(KeywordField::Title).is_empty());
+
+    let job = Job {
+        title: "Développeur Rust".to_string(),
+        company: "Acme".to_string(),
+        description: "Deux jours de teletravail par semaine.".to_string(),
+        ..Default::default()
+    };
+    assert!(filters.matches(&job));
+    assert!(!filters.matches(&Job {
+        company: "Acme Intérim".to_string(),
+        ..job.clone()
+    }));
+    assert!(!filters.matches(&Job {
+        description: "Poste sur site.".to_string(),
+        ..job
+    }));
+}
@@ -0,0 +1,128 @@
+use scraptain::location::{distance_km, load_communes, normalize};
+
+#[test]
+fn test_board_location_formats() {
+    let hellowork = normalize("Lyon 3e - 69").unwrap();
+    assert_eq!(hellowork.city.as_deref(), Some("Lyon"));
+    assert_eq!(hellowork.postcode.as_deref(), Some("69003"));
+    assert_eq!(hellowork.department.as_deref(), Some("Rhône"));
+    assert_eq!(hellowork.department_code.as_deref(), Some("69"));
+    assert_eq!(hellowork.region.as_deref(), Some("Auvergne-Rhône-Alpes"));
+    assert_eq!(hellowork.country.as_deref(), Some("France"));
+
+    let linkedin = normalize("Lyon, Auvergne-Rhône-Alpes, France").unwrap();
+    assert_eq!(linkedin.city.as_deref(), Some("Lyon"));
+    assert_eq!(linkedin.postcode.as_deref(), Some("69001"));
+    assert_eq!(linkedin.region, hellowork.region);
+
+    let wttj = normalize("Lyon").unwrap();
+    assert_eq!(wttj.city.as_deref(), Some("Lyon"));
+    assert!(wttj.lat.is_some() && wttj.lon.is_some());
+}
+
+#[test]
+fn test_ambiguous_names() {
+    let department = normalize("Seine-Saint-Denis").unwrap();
+    assert_eq!(department.city, None);
+    assert_eq!(department.department_code.as_deref(), Some("93"));
+
+    let reunion = normalize("Saint-Denis, La Réunion").unwrap();
+    assert_eq!(reunion.postcode.as_deref(), Some("97400"));
+
+    let vienne = normalize("Vienne (86)").unwrap();
+    assert_eq!(vienne.city, None);
+    assert_eq!(vienne.department.as_deref(), Some("Vienne"));
+
+    let isere = normalize("Vienne - 38").unwrap();
+    assert_eq!(isere.city.as_deref(), Some("Vienne"));
+}
+
+#[test]
+fn test_postcodes_aliases_and_regions() {
+    let paris = normalize("75015 Paris").unwrap();
+    assert_eq!(paris.postcode.as_deref(), Some("75015"));
+    assert_eq!(paris.region.as_deref(), Some("Île-de-France"));
+
+    let defense = normalize("Paris La Défense").unwrap();
+    assert_eq!(defense.city.as_deref(), Some("Puteaux"));
+
+    let st = normalize("St-Étienne (42)").unwrap();
+    assert_eq!(st.city.as_deref(), Some("Saint-Étienne"));
+
+    let region = normalize("Auvergne-Rhône-Alpes, France").unwrap();
+    assert_eq!(region.city, None);
+    assert_eq!(region.department, None);
+    assert_eq!(region.region.as_deref(), Some("Auvergne-Rhône-Alpes"));
+
+    let geneva = normalize("Genève, Suisse").unwrap();
+    assert_eq!(geneva.city.as_deref(), Some("Geneva"));
+    assert_eq!(geneva.country.as_deref(), Some("Suisse"));
+
+    assert!(normalize("Télétravail").is_none());
+}
+
+#[test]
+fn test_distance() {
+    let lyon = normalize("Lyon").unwrap().coordinates().unwrap();
+    let villeurbanne = normalize("Villeurbanne").unwrap().coordinates().unwrap();
+    let paris = normalize("Paris").unwrap().coordinates().unwrap();
+    assert!(distance_km(lyon, villeurbanne) < 10.0);
+    let lyon_paris = distance_km(lyon, paris);
+    assert!((380.0..400.0).contains(&lyon_paris), "{}", lyon_paris);
+}
+
+#[test]
+fn test_arrondissements_need_a_suffix_or_a_bare_number() {
+    let department = normalize("Marseille - 13").unwrap();
+    assert_eq!(department.postcode.as_deref(), Some("13001"));
+    assert_eq!(department.department_code.as_deref(), Some("13"));
+
+    let same_number = normalize("Marseille 13").unwrap();
+    assert_eq!(same_number.postcode.as_deref(), Some("13001"));
+    assert_eq!(same_number.department_code.as_deref(), Some("13"));
+
+    assert_eq!(
+        normalize("Marseille 13e").unwrap().postcode.as_deref(),
+        Some("13013")
+    );
+    assert_eq!(
+        normalize("Paris 15").unwrap().postcode.as_deref(),
+        Some("75015")
+    );
+    assert_eq!(
+        normalize("Lyon 2ème arrondissement")
+            .unwrap()
+            .postcode
+            .as_deref(),
+        Some("69002")
+    );
+    let hyphenated = normalize("Paris - 75").unwrap();
+    assert_eq!(hyphenated.postcode.as_deref(), Some("75001"));
+    assert_eq!(hyphenated.department_code.as_deref(), Some("75"));
+}
+
+#[test]
+fn test_non_ascii_digits_are_not_postcodes() {
+    let lyon = normalize("Lyon ６９００３").unwrap();
+    assert_eq!(lyon.city.as_deref(), Some("Lyon"));
+    assert_eq!(lyon.postcode.as_deref(), Some("69001"));
+    assert!(normalize("Rhône ٦٩").is_some());
+}
+
+#[test]
+fn test_extra_communes_can_be_loaded() {
+    assert!(normalize("Mornant").is_none());
+    assert_eq!(
+        load_communes("name,postcode,departement,lat,lon\nMornant,69440,69,45.619,4.672\n"),
+        Ok(1)
+    );
+    let mornant = normalize("Mornant (69)").unwrap();
+    assert_eq!(mornant.postcode.as_deref(), Some("69440"));
+    assert_eq!(mornant.department_code.as_deref(), Some("69"));
+    assert!(distance_km(mornant.coordinates().unwrap(), (45.764, 4.836)) < 25.0);
+    assert_eq!(normalize("Lyon").unwrap().city.as_deref(), Some("Lyon"));
+
+    assert!(
+        load_communes("name,postcode,departement,lat,lon\nNulle Part,6944,69,x,4.6\n").is_err()
+    );
+}
@@ -0,0 +1,61 @@
+use scraptain::markdown::html_to_markdown;
+
+#[test]
+fn test_headings_paragraphs_and_emphasis() {
+    let html = r#"
+        <div>
+            <h2>Votre mission</h2>
+            <p>Rejoindre une équipe <strong>produit</strong> de 12 personnes.</p>
+            <p>Vous travaillerez en <em>télétravail partiel</em>.</p>
+        </div>
+    "#;
+    assert_eq!(
+        html_to_markdown(html),
+        "## Votre mission\n\nRejoindre une équipe **produit** de 12 personnes.\n\nVous travaillerez en *télétravail partiel*."
+    );
+}
+
+#[test]
+fn test_lists_keep_their_structure() {
+    let html = r#"
+        <p>Profil :</p>
+        <ul>
+            <li>3 ans d'expérience en <b>Rust</b></li>
+            <li>Maîtrise de SQL
+                <ol><li>PostgreSQL</li><li>SQLite</li></ol>
+            </li>
+        </ul>
+    "#;
+    assert_eq!(
+        html_to_markdown(html),
+        "Profil :\n\n- 3 ans d'expérience en **Rust**\n- Maîtrise de SQL\n  1. PostgreSQL\n  2. SQLite"
+    );
+}
+
+#[test]
+fn test_links_lose_tracking_parameters() {
+    let html = r#"<p>Voir <a href="https://example.com/jobs?id=4&utm_source=linkedin&trk=abc" data-tracking="x">l'offre</a>.</p>"#;
+    assert_eq!(
+        html_to_markdown(html),
+        "Voir [l'offre](https://example.com/jobs?id=4)."
+    );
+}
+
+#[test]
+fn test_scripts_styles_and_empty_links_are_stripped() {
+    let html = r#"
+        <style>.x { color: red }</style>
+        <p>Poste<script>track("view")</script> en CDI<br>à Lyon</p>
+        <a href="javascript:void(0)">Postuler</a>
+    "#;
+    assert_eq!(html_to_markdown(html), "Poste en CDI\nà Lyon\n\nPostuler");
+}
+
+#[test]
+fn test_text_metacharacters_are_escaped() {
+    let html = r#"<p>#1 du secteur, salaire *très* attractif [selon profil], poste_id 42</p><p>Stack : <code>snake_case_name</code></p>"#;
+    assert_eq!(
+        html_to_markdown(html),
+        "\\#1 du secteur, salaire \\*très\\* attractif \\[selon profil\\], poste\\_id 42\n\nStack : `snake_case_name`"
+    );
+}
@@ -0,0 +1,130 @@
+use scraptain::scoring::{remote_mode, score, sort_by_score, Profile, RemoteMode};
+use scraptain::skills::Taxonomy;
+use scraptain::{location, Job, Salary};
+
+fn job(title: &str, description: &str) -> Job {
+    let taxonomy = Taxonomy::default();
+    Job {
+        title: title.to_string(),
+        description: description.to_string(),
+        skills: taxonomy.extract(&[title, description]),
+        ..Default::default()
+    }
+}
+
+fn profile() -> Profile {
+    Profile {
+        skills: vec![("Rust".to_string(), 5.0), ("Postgres".to_string(), 2.0)],
+        excluded_keywords: vec!["stage".to_string()],
+        preferred_locations: vec!["Lyon".to_string()],
+        min_salary: Some(50000.0),
+        contracts: vec!["CDI".to_string()],
+        remote: Some(RemoteMode::Hybrid),
+    }
+}
+
+#[test]
+fn test_factors_explain_the_score() {
+    let mut offer = job(
+        "Développeur Rust",
+        "API en Rust et PostgreSQL, 2 jours de télétravail par semaine.",
+    );
+    offer.location = "Lyon 3e".to_string();
+    offer.contract = Some("CDI".to_string());
+    offer.salary = Some(Salary {
+        min: Some(4000.0),
+        max: Some(4500.0),
+        currency: Some("EUR".to_string()),
+        period: Some("MONTH".to_string()),
+    });
+
+    let score = score(&offer, &profile(), &Taxonomy::default());
+    let factors: Vec<(&str, f64)> = score
+        .factors
+        .iter()
+        .map(|f| (f.name.as_str(), f.points))
+        .collect();
+    assert_eq!(
+        factors,
+        vec![
+            ("skill", 5.0),
+            ("skill", 2.0),
+            ("location", 3.0),
+            ("salary", 2.0),
+            ("contract", 2.0),
+            ("remote", 2.0),
+        ]
+    );
+    assert_eq!(score.total, 16.0);
+    assert_eq!(score.factors[1].reason, "mentions PostgreSQL");
+}
+
+#[test]
+fn test_penalties() {
+    let mut offer = job(
+        "Stage Développeur Java",
+        "Poste sur site, pas de télétravail.",
+    );
+    offer.normalized_location = location::normalize("Villeurbanne (69)");
+    offer.contract = Some("Stage".to_string());
+    offer.salary = Some(Salary {
+        min: Some(1200.0),
+        max: None,
+        currency: Some("EUR".to_string()),
+        period: Some("MONTH".to_string()),
+    });
+
+    let score = score(&offer, &profile(), &Taxonomy::default());
+    let names: Vec<&str> = score.factors.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["excluded_keyword", "salary", "contract", "remote"]
+    );
+    assert_eq!(score.total, -16.0);
+    assert_eq!(remote_mode(&offer), Some(RemoteMode::OnSite));
+}
+
+#[test]
+fn test_sort_by_score() {
+    let profile = profile();
+    let taxonomy = Taxonomy::default();
+    let mut jobs: Vec<Job> = ["Développeur Java", "Développeur Rust", "Stage Rust"]
+        .iter()
+        .map(|title| {
+            let mut job = job(title, "");
+            job.score = Some(score(&job, &profile, &taxonomy));
+            job
+        })
+        .collect();
+    jobs.push(job("Non noté", ""));
+
+    sort_by_score(&mut jobs);
+    let titles: Vec<&str> = jobs.iter().map(|j| j.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        vec![
+            "Développeur Rust",
+            "Développeur Java",
+            "Stage Rust",
+            "Non noté"
+        ]
+    );
+}
+
+#[test]
+fn test_negated_remote_phrases_are_skipped() {
+    let mode = |description: &str| remote_mode(&job("Développeur Rust", description));
+    assert_eq!(
+        mode("Pas de full remote possible, 2 jours de télétravail par semaine."),
+        Some(RemoteMode::Hybrid)
+    );
+    assert_eq!(mode("Le full remote n'est pas possible."), None);
+    assert_eq!(
+        mode("Pas de télétravail partiel, poste sur site."),
+        Some(RemoteMode::OnSite)
+    );
+    assert_eq!(
+        mode("Poste en full remote, réunion sur site une fois par an."),
+        Some(RemoteMode::Full)
+    );
+}
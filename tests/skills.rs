@@ -0,0 +1,97 @@
+use scraptain::skills::{count, Skill, SkillCategory, Taxonomy};
+use scraptain::Job;
+
+#[test]
+fn test_aliases_and_word_boundaries() {
+    let taxonomy = Taxonomy::default();
+    let skills = taxonomy.extract(&[
+        "Développeur Backend Rust / Golang",
+        "Stack : Rust (Tokio, Axum), Postgres, JS côté front avec Node.js. \
+         Déploiement sur AWS via K8s. Architecture scalable, 16 Go de RAM.",
+    ]);
+    assert_eq!(
+        skills,
+        vec![
+            "Rust",
+            "JavaScript",
+            "Go",
+            "Node.js",
+            "Tokio",
+            "Axum",
+            "AWS",
+            "Kubernetes",
+            "PostgreSQL"
+        ]
+    );
+    assert!(taxonomy
+        .extract(&["JavaScript, TypeScript"])
+        .iter()
+        .all(|s| s != "Java"));
+    assert_eq!(
+        taxonomy.extract(&["C++ et C#, .NET Core"]),
+        vec!["C++", "C#", ".NET"]
+    );
+}
+
+#[test]
+fn test_custom_taxonomy() {
+    let taxonomy = Taxonomy::from_json(
+        r#"{"skills": [{"name": "Bevy", "category": "framework", "aliases": ["bevy engine"]}]}"#,
+    )
+    .unwrap()
+    .with_skill(Skill::new("Leptos", SkillCategory::Framework).alias("leptos-rs"));
+
+    assert_eq!(
+        taxonomy.extract(&["Jeu vidéo avec Bevy Engine et un site en leptos-rs"]),
+        vec!["Bevy", "Leptos"]
+    );
+    assert_eq!(
+        taxonomy.canonical("bevy engine").map(|s| s.category),
+        Some(SkillCategory::Framework)
+    );
+    assert!(taxonomy.extract(&["Rust"]).is_empty());
+}
+
+#[test]
+fn test_required_and_excluded_skills() {
+    let taxonomy = Taxonomy::default();
+    let skills = vec!["Rust".to_string(), "PostgreSQL".to_string()];
+    let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+    assert!(taxonomy.satisfies(&skills, &names(&["rust", "Postgres"]), &[]));
+    assert!(!taxonomy.satisfies(&skills, &names(&["Rust", "Kafka"]), &[]));
+    assert!(!taxonomy.satisfies(&skills, &[], &names(&["psql"])));
+    assert!(taxonomy.satisfies(&skills, &[], &names(&["Java"])));
+
+    let jobs = [
+        Job {
+            skills: names(&["Rust", "AWS"]),
+            ..Default::default()
+        },
+        Job {
+            skills: names(&["Python", "AWS"]),
+            ..Default::default()
+        },
+    ];
+    assert_eq!(
+        count(&jobs),
+        vec![
+            ("AWS".to_string(), 2),
+            ("Python".to_string(), 1),
+            ("Rust".to_string(), 1)
+        ]
+    );
+}
+
+#[test]
+fn test_ambiguous_words_in_prose_are_not_skills() {
+    let taxonomy = Taxonomy::default();
+    assert!(taxonomy
+        .extract(&["Ingénieur chez Shell, au printemps (spring) ; vous devez react rapidement"])
+        .is_empty());
+    assert_eq!(
+        taxonomy.extract(&["Front en React, back en Spring Boot, scripts Bash"]),
+        vec!["Bash", "React", "Spring"]
+    );
+    assert_eq!(taxonomy.extract(&["Stack reactjs"]), vec!["React"]);
+}
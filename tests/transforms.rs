@@ -0,0 +1,136 @@
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use scraptain::dates::FixedClock;
+use scraptain::transforms::{self, apply, apply_with_clock, linkedin_id, Transform};
+use std::borrow::Cow;
+
+#[test]
+fn test_pipeline_steps() {
+    let pipeline = [
+        Transform::CollapseWhitespace,
+        Transform::RegexCapture {
+            pattern: Cow::Borrowed(r"Réf\.?\s*:\s*(\S+)"),
+            group: 1,
+        },
+        Transform::Lowercase,
+        Transform::StripPrefix {
+            prefix: Cow::Borrowed("job-"),
+        },
+    ];
+    assert_eq!(
+        apply(&pipeline, "  Offre   Réf : JOB-4821\n publiée "),
+        Some("4821".to_string())
+    );
+    assert_eq!(apply(&pipeline, "Pas de référence"), None);
+
+    let linkedin_id = [Transform::SplitNth {
+        separator: Cow::Borrowed(":"),
+        index: -1,
+    }];
+    assert_eq!(
+        apply(&linkedin_id, "urn:li:jobPosting:3912345678"),
+        Some("3912345678".to_string())
+    );
+
+    let replace = [
+        Transform::Replace {
+            pattern: Cow::Borrowed(r"\s*\(H/F\)"),
+            with: Cow::Borrowed(""),
+        },
+        Transform::Trim,
+    ];
+    assert_eq!(
+        apply(&replace, " Développeur Rust (H/F) "),
+        Some("Développeur Rust".to_string())
+    );
+}
+
+#[test]
+fn test_url_join_and_dates() {
+    let join = [
+        Transform::StripSuffix {
+            suffix: Cow::Borrowed("?from=list"),
+        },
+        Transform::UrlJoin {
+            base: Cow::Borrowed("https://www.welcometothejungle.com/fr/"),
+        },
+    ];
+    assert_eq!(
+        apply(&join, "/fr/companies/acme/jobs/dev?from=list"),
+        Some("https://www.welcometothejungle.com/fr/companies/acme/jobs/dev".to_string())
+    );
+
+    let custom = [Transform::ParseDate {
+        format: Some(Cow::Borrowed("%d.%m.%Y")),
+    }];
+    assert_eq!(apply(&custom, "14.03.2025"), Some("2025-03-14".to_string()));
+
+    let label = [Transform::ParseDate { format: None }];
+    assert_eq!(apply(&label, "14/03/2025"), Some("2025-03-14".to_string()));
+    assert_eq!(apply(&label, "bientôt"), None);
+
+    let clock = FixedClock(Utc.with_ymd_and_hms(2025, 3, 20, 9, 0, 0).unwrap());
+    assert_eq!(
+        apply_with_clock(&label, "il y a 3 jours", &clock),
+        Some("2025-03-17T00:00:00+00:00".to_string())
+    );
+}
+
+#[test]
+fn test_linkedin_id_wrapper_is_kept() {
+    assert_eq!(linkedin_id("urn:li:jobPosting:3912345678"), "3912345678");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_date_wrappers_are_kept() {
+    let today = Utc::now().date_naive();
+    assert_eq!(
+        transforms::parse_date("2025-03-12T09:30:00Z"),
+        NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()
+    );
+    assert_eq!(
+        transforms::parse_date("2025-03-12"),
+        NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()
+    );
+    assert_eq!(transforms::parse_date("not a date"), today);
+    assert_eq!(
+        transforms::hellowork_date("il y a 2 jours"),
+        (today - Duration::days(2)).to_string()
+    );
+    assert_eq!(
+        transforms::hellowork_date("1 semaine"),
+        (today - Duration::weeks(1)).to_string()
+    );
+}
+
+#[test]
+fn test_pipeline_round_trips_through_json() {
+    let json = r#"[
+        {"op": "regex_capture", "pattern": "(\\d+) jours"},
+        {"op": "split_nth", "separator": "-", "index": 0},
+        {"op": "trim"},
+        {"op": "parse_date", "format": "%Y-%m-%d"}
+    ]"#;
+    let pipeline: Vec<Transform> = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        pipeline[0],
+        Transform::RegexCapture {
+            pattern: Cow::Borrowed(r"(\d+) jours"),
+            group: 1,
+        }
+    );
+    let serialized = serde_json::to_string(&pipeline).unwrap();
+    let reparsed: Vec<Transform> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(reparsed, pipeline);
+}
+
+#[test]
+fn test_custom_transforms_capture_state() {
+    let prefix = String::from("hw-");
+    let pipeline = [
+        Transform::Trim,
+        Transform::custom(move |value| format!("{}{}", prefix, value)),
+    ];
+    assert_eq!(apply(&pipeline, " 4821 "), Some("hw-4821".to_string()));
+    assert!(serde_json::to_string(&pipeline).is_err());
+}
@@ -0,0 +1,102 @@
+use scraper::Html;
+use scraptain::models::Selects;
+use scraptain::xpath::XPath;
+
+const PAGE: &str = r#"
+    <div id="offer">
+        <h2>Informations</h2>
+        <dl>
+            <dt>Contrat</dt><dd>CDI</dd>
+            <dt> Salaire </dt><dd>45 - 55 k€</dd>
+            <dt>Télétravail</dt><dd>2 jours</dd>
+        </dl>
+        <ul class="tags"><li>Rust</li><li>SQL</li><li class="more">+3</li></ul>
+        <a href="/apply" data-cy="apply">Postuler</a>
+    </div>
+"#;
+
+fn texts(expression: &str) -> Vec<String> {
+    let document = Html::parse_document(PAGE);
+    XPath::parse(expression)
+        .unwrap_or_else(|e| panic!("{}: {}", expression, e))
+        .select(&document)
+        .iter()
+        .map(|e| e.text().collect::<String>())
+        .collect()
+}
+
+#[test]
+fn test_sibling_and_text_predicates() {
+    assert_eq!(
+        texts("//dt[normalize-space()='Salaire']/following-sibling::dd[1]"),
+        vec!["45 - 55 k€"]
+    );
+    assert_eq!(
+        texts("//dd[preceding-sibling::dt[1][contains(., 'Contrat')]]"),
+        vec!["CDI"]
+    );
+    assert_eq!(
+        texts("//dt[text()='Télétravail']/../dd[last()]"),
+        vec!["2 jours"]
+    );
+}
+
+#[test]
+fn test_positions_attributes_and_boolean_logic() {
+    assert_eq!(texts("//ul[@class='tags']/li[2]"), vec!["SQL"]);
+    assert_eq!(
+        texts("//ul/li[not(@class) and position() < 3]"),
+        vec!["Rust", "SQL"]
+    );
+    assert_eq!(
+        texts("//*[@data-cy='apply' or starts-with(., 'Info')]"),
+        vec!["Informations", "Postuler"]
+    );
+    assert_eq!(
+        texts("/html/body/div[@id='offer']/h2"),
+        vec!["Informations"]
+    );
+    assert!(texts("//table").is_empty());
+}
+
+#[test]
+fn test_invalid_expressions() {
+    for expression in ["//dt[", "//dt[foo()]", "//dt/unknown::dd", "//dt[@]"] {
+        assert!(XPath::parse(expression).is_err(), "{}", expression);
+    }
+}
+
+#[test]
+fn test_selects_mix_css_and_xpath() {
+    let document = Html::parse_document(PAGE);
+    let css = Selects::Css("ul.tags li.more");
+    let xpath = Selects::XPath("//dt[.='Contrat']/following-sibling::dd[1]");
+
+    assert_eq!(css.select(&document).unwrap().len(), 1);
+    assert_eq!(
+        xpath.select(&document).unwrap()[0]
+            .text()
+            .collect::<String>(),
+        "CDI"
+    );
+    assert_eq!(
+        xpath.to_string(),
+        "xpath://dt[.='Contrat']/following-sibling::dd[1]"
+    );
+    assert!(Selects::XPath("//dt[").validate().is_err());
+    assert!(Selects::Css("dt[").validate().is_err());
+}
+
+#[test]
+fn test_text_and_attribute_results_are_rejected() {
+    for expression in ["//h2/text()", "//a/@href", "//dl/dt[1]/text()"] {
+        assert!(XPath::parse(expression).is_err(), "{}", expression);
+        assert!(
+            Selects::XPath(expression).validate().is_err(),
+            "{}",
+            expression
+        );
+    }
+    assert_eq!(texts("//dt[text()='Contrat']"), vec!["Contrat"]);
+    assert_eq!(texts("//a[@href='/apply']"), vec!["Postuler"]);
+}